@@ -0,0 +1,457 @@
+//! A shape-typed n-dimensional array backed by a [Vec].
+
+use crate::{
+    bounded::BoundedIndex,
+    common::*,
+    control::{AssertLess, AssertSame, AssertTrue},
+    dyn_::{Dyn, Mismatch},
+    list::{Cons, Len, LenOp, List, Nil, ToUsizeVec, ValueRemove},
+    shape::{
+        CanReshape, CanReshapeOp, IsPermutation, IsPermutationOp, ValueBroadcast, ValuePermApply,
+        ValuePermute,
+    },
+};
+use std::iter;
+use typenum::IsLessOrEqual;
+
+// index checks
+
+/// Checks a static index against a static or `Dyn<usize>` dimension.
+///
+/// Static dimensions are checked at compile time, and `Dyn<usize>` dimensions at runtime.
+pub trait DimIndex<Index> {
+    fn check_index(&self);
+}
+
+impl<Index> DimIndex<Index> for UTerm
+where
+    (): AssertLess<Index, UTerm>,
+{
+    fn check_index(&self) {}
+}
+
+impl<Index, U, B> DimIndex<Index> for UInt<U, B>
+where
+    (): AssertLess<Index, UInt<U, B>>,
+{
+    fn check_index(&self) {}
+}
+
+impl<Index> DimIndex<Index> for Dyn<usize>
+where
+    Index: Unsigned,
+{
+    fn check_index(&self) {
+        assert!(
+            Index::USIZE < self.0,
+            "the index {} is out of bounds of size {}",
+            Index::USIZE,
+            self.0
+        );
+    }
+}
+
+/// Checks a static multi-index against a shape, and gives the runtime index.
+pub trait ShapeIndex<Index>
+where
+    Self: List,
+    Index: List,
+{
+    fn index(&self) -> Vec<usize>;
+}
+
+impl<Dim, DimTail, Index, IndexTail> ShapeIndex<Cons<Index, IndexTail>> for Cons<Dim, DimTail>
+where
+    Dim: DimIndex<Index>,
+    DimTail: List + ShapeIndex<IndexTail>,
+    Index: Unsigned,
+    IndexTail: List,
+{
+    fn index(&self) -> Vec<usize> {
+        self.head.check_index();
+        let mut index = vec![Index::USIZE];
+        index.extend(self.tail.index());
+        index
+    }
+}
+
+impl ShapeIndex<Nil> for Nil {
+    fn index(&self) -> Vec<usize> {
+        vec![]
+    }
+}
+
+/// Computes the offset of a list of [BoundedIndex]es in a static shape, where each bound
+/// does not exceed its dimension.
+pub trait BoundedShapeIndex<Index>
+where
+    Self: List,
+    Index: List,
+{
+    fn offset(index: &Index) -> usize;
+
+    fn len() -> usize;
+}
+
+impl<Dim, DimTail, N, IndexTail> BoundedShapeIndex<Cons<BoundedIndex<N>, IndexTail>>
+    for Cons<Dim, DimTail>
+where
+    Dim: Unsigned,
+    DimTail: List + BoundedShapeIndex<IndexTail>,
+    N: Unsigned + IsLessOrEqual<Dim, Output = B1>,
+    IndexTail: List,
+{
+    fn offset(index: &Cons<BoundedIndex<N>, IndexTail>) -> usize {
+        index.head.get() * DimTail::len() + DimTail::offset(&index.tail)
+    }
+
+    fn len() -> usize {
+        Dim::USIZE * DimTail::len()
+    }
+}
+
+impl BoundedShapeIndex<Nil> for Nil {
+    fn offset(_index: &Nil) -> usize {
+        0
+    }
+
+    fn len() -> usize {
+        1
+    }
+}
+
+// array
+
+/// An n-dimensional array whose shape is a [List] of typenum and `Dyn<usize>` dimensions.
+///
+/// The elements are stored in a [Vec] in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypedArray<T, Shape>
+where
+    Shape: List,
+{
+    data: Vec<T>,
+    shape: Shape,
+}
+
+impl<T, Shape> TypedArray<T, Shape>
+where
+    Shape: List + ToUsizeVec,
+{
+    /// Builds an array from row-major elements, and fails if the number of elements
+    /// mismatches the shape.
+    pub fn from_vec(shape: Shape, data: Vec<T>) -> Result<Self, Mismatch<usize>> {
+        let expected: usize = shape.to_usize_vec().iter().product();
+        if data.len() == expected {
+            Ok(Self { data, shape })
+        } else {
+            Err(Mismatch {
+                expected,
+                actual: data.len(),
+            })
+        }
+    }
+
+    /// Builds an array filled with copies of the value.
+    pub fn full(shape: Shape, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let len = shape.to_usize_vec().iter().product();
+        Self {
+            data: vec![value; len],
+            shape,
+        }
+    }
+
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    pub fn dims(&self) -> Vec<usize> {
+        self.shape.to_usize_vec()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Borrows the element at a static multi-index.
+    pub fn get<Index>(&self) -> &T
+    where
+        Index: List,
+        Shape: ShapeIndex<Index>,
+    {
+        let index = ShapeIndex::<Index>::index(&self.shape);
+        let offset = index
+            .iter()
+            .zip(row_major_strides(&self.dims()))
+            .map(|(position, stride)| position * stride)
+            .sum::<usize>();
+        &self.data[offset]
+    }
+
+    /// Borrows the element at a list of [BoundedIndex]es without bounds checks.
+    pub fn at<Index>(&self, index: &Index) -> &T
+    where
+        Index: List,
+        Shape: BoundedShapeIndex<Index>,
+    {
+        let offset = Shape::offset(index);
+        debug_assert!(offset < self.data.len());
+        // SAFETY: every index is below its dimension, and the data length equals the product
+        // of dimensions, which is checked by every constructor.
+        unsafe { self.data.get_unchecked(offset) }
+    }
+
+    /// Reshapes to a static shape with the same number of elements.
+    pub fn reshape<NewShape>(self) -> TypedArray<T, NewShape>
+    where
+        NewShape: List + Default,
+        (): CanReshape<Shape, NewShape> + AssertTrue<CanReshapeOp<Shape, NewShape>>,
+    {
+        TypedArray {
+            data: self.data,
+            shape: NewShape::default(),
+        }
+    }
+
+    /// Reshapes to a shape of static and `Dyn<usize>` dimensions, and fails if the numbers
+    /// of elements differ.
+    pub fn try_reshape<NewShape>(
+        self,
+        shape: NewShape,
+    ) -> Result<TypedArray<T, NewShape>, Mismatch<usize>>
+    where
+        NewShape: List + ToUsizeVec,
+    {
+        TypedArray::from_vec(shape, self.data)
+    }
+
+    /// Permutes the axes by the static permutation `Perm`.
+    pub fn transpose<Perm>(&self) -> TypedArray<T, <Shape as ValuePermApply<Perm>>::Output>
+    where
+        T: Clone,
+        Perm: List + Default + ToUsizeVec,
+        Shape: ValuePermApply<Perm>,
+        <Shape as ValuePermApply<Perm>>::Output: ToUsizeVec,
+        (): IsPermutation<Perm> + AssertTrue<IsPermutationOp<Perm>>,
+        (): Len<Shape> + Len<Perm> + AssertSame<LenOp<Shape>, LenOp<Perm>>,
+    {
+        let perm = Perm::default().to_usize_vec();
+        let strides = row_major_strides(&self.dims());
+        let shape = self.shape.permute::<Perm>();
+        let dims = shape.to_usize_vec();
+        let data = multi_indices(&dims)
+            .map(|index| {
+                let offset = index
+                    .iter()
+                    .zip(&perm)
+                    .map(|(position, &axis)| position * strides[axis])
+                    .sum::<usize>();
+                self.data[offset].clone()
+            })
+            .collect();
+        TypedArray { data, shape }
+    }
+
+    pub fn map<U, F>(&self, f: F) -> TypedArray<U, Shape>
+    where
+        Shape: Clone,
+        F: FnMut(&T) -> U,
+    {
+        TypedArray {
+            data: self.data.iter().map(f).collect(),
+            shape: self.shape.clone(),
+        }
+    }
+
+    /// Combines the elements of two arrays whose shapes broadcast by NumPy rules.
+    pub fn zip_with<U, V, RhsShape, F>(
+        &self,
+        rhs: &TypedArray<U, RhsShape>,
+        mut f: F,
+    ) -> TypedArray<V, <Shape as ValueBroadcast<RhsShape>>::Output>
+    where
+        Shape: ValueBroadcast<RhsShape>,
+        RhsShape: List + ToUsizeVec,
+        <Shape as ValueBroadcast<RhsShape>>::Output: ToUsizeVec,
+        F: FnMut(&T, &U) -> V,
+    {
+        let shape = ValueBroadcast::broadcast(&self.shape, &rhs.shape);
+        let dims = shape.to_usize_vec();
+        let lhs_dims = self.dims();
+        let rhs_dims = rhs.dims();
+        let data = multi_indices(&dims)
+            .map(|index| {
+                let lhs = &self.data[broadcast_offset(&index, &lhs_dims)];
+                let rhs = &rhs.data[broadcast_offset(&index, &rhs_dims)];
+                f(lhs, rhs)
+            })
+            .collect();
+        TypedArray { data, shape }
+    }
+
+    /// Sums up the elements along the static axis, and removes the axis.
+    pub fn sum_axis<Axis>(&self) -> TypedArray<T, <Shape as ValueRemove<Axis>>::Output>
+    where
+        T: Clone + iter::Sum,
+        Axis: Unsigned,
+        Shape: Clone + ValueRemove<Axis>,
+        <Shape as ValueRemove<Axis>>::Output: ToUsizeVec,
+        (): Len<Shape> + AssertLess<Axis, LenOp<Shape>>,
+    {
+        let dims = self.dims();
+        let axis = Axis::USIZE;
+        let len = dims[axis];
+        let outer: usize = dims[..axis].iter().product();
+        let inner: usize = dims[(axis + 1)..].iter().product();
+        let data = (0..outer)
+            .flat_map(|outer_index| (0..inner).map(move |inner_index| (outer_index, inner_index)))
+            .map(|(outer_index, inner_index)| {
+                (0..len)
+                    .map(|position| {
+                        self.data[(outer_index * len + position) * inner + inner_index].clone()
+                    })
+                    .sum()
+            })
+            .collect();
+        TypedArray {
+            data,
+            shape: ValueRemove::<Axis>::remove(self.shape.clone()),
+        }
+    }
+}
+
+// helpers
+
+fn row_major_strides(dims: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; dims.len()];
+    for axis in (0..dims.len().saturating_sub(1)).rev() {
+        strides[axis] = strides[axis + 1] * dims[axis + 1];
+    }
+    strides
+}
+
+fn multi_indices(dims: &[usize]) -> impl Iterator<Item = Vec<usize>> + '_ {
+    let len: usize = dims.iter().product();
+    (0..len).map(move |offset| {
+        let mut remaining = offset;
+        let mut index = vec![0; dims.len()];
+        for (position, &dim) in index.iter_mut().zip(dims).rev() {
+            *position = remaining % dim;
+            remaining /= dim;
+        }
+        index
+    })
+}
+
+fn broadcast_offset(index: &[usize], dims: &[usize]) -> usize {
+    let index = &index[(index.len() - dims.len())..];
+    index
+        .iter()
+        .zip(dims)
+        .zip(row_major_strides(dims))
+        .map(|((&position, &dim), stride)| if dim == 1 { 0 } else { position * stride })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{list, List, Permute};
+    use typenum::consts::*;
+
+    #[test]
+    fn typed_array_test() {
+        let array = TypedArray::from_vec(list![U2::new(), U3::new()], (0..6).collect()).unwrap();
+        assert_eq!(array.dims(), vec![2, 3]);
+        assert_eq!(*array.get::<List![U0, U2]>(), 2);
+        assert_eq!(*array.get::<List![U1, U1]>(), 4);
+        assert_eq!(
+            TypedArray::from_vec(list![U2::new(), U3::new()], vec![0; 5]),
+            Err(Mismatch {
+                expected: 6,
+                actual: 5
+            })
+        );
+
+        let reshaped: TypedArray<i32, List![U3, U2]> = array.clone().reshape();
+        assert_eq!(reshaped.as_slice(), &[0, 1, 2, 3, 4, 5]);
+
+        let transposed = array.transpose::<Permute![1, 0]>();
+        let _: &List![U3, U2] = transposed.shape();
+        assert_eq!(transposed.as_slice(), &[0, 3, 1, 4, 2, 5]);
+
+        let doubled = array.map(|value| value * 2);
+        assert_eq!(doubled.as_slice(), &[0, 2, 4, 6, 8, 10]);
+
+        let rows = array.sum_axis::<U0>();
+        let _: &List![U3] = rows.shape();
+        assert_eq!(rows.as_slice(), &[3, 5, 7]);
+        let cols = array.sum_axis::<U1>();
+        assert_eq!(cols.as_slice(), &[3, 12]);
+    }
+
+    #[test]
+    fn typed_array_bounded_index_test() {
+        let array = TypedArray::from_vec(list![U2::new(), U3::new()], (0..6).collect()).unwrap();
+        let sum: i32 = BoundedIndex::<U2>::iter()
+            .flat_map(|row| BoundedIndex::<U3>::iter().map(move |col| list![row, col]))
+            .map(|index| *array.at(&index))
+            .sum();
+        assert_eq!(sum, 15);
+
+        let index = list![
+            BoundedIndex::<U1>::checked_new(0).unwrap(),
+            BoundedIndex::<U3>::checked_new(2).unwrap()
+        ];
+        assert_eq!(*array.at(&index), 2);
+    }
+
+    #[test]
+    fn typed_array_broadcast_test() {
+        let lhs = TypedArray::from_vec(list![U2::new(), U1::new()], vec![10, 20]).unwrap();
+        let rhs = TypedArray::from_vec(list![U3::new()], vec![1, 2, 3]).unwrap();
+        let sum = lhs.zip_with(&rhs, |lhs, rhs| lhs + rhs);
+        let _: &List![U2, U3] = sum.shape();
+        assert_eq!(sum.as_slice(), &[11, 12, 13, 21, 22, 23]);
+    }
+
+    #[test]
+    fn typed_array_dyn_test() {
+        let array = TypedArray::from_vec(list![Dyn(2usize), U3::new()], (0..6).collect()).unwrap();
+        assert_eq!(*array.get::<List![U1, U2]>(), 5);
+
+        let reshaped = array
+            .clone()
+            .try_reshape(list![U3::new(), Dyn(2usize)])
+            .unwrap();
+        assert_eq!(reshaped.dims(), vec![3, 2]);
+        assert!(array.clone().try_reshape(list![Dyn(4usize)]).is_err());
+
+        let transposed = array.transpose::<Permute![1, 0]>();
+        let _: &List![U3, Dyn<usize>] = transposed.shape();
+        assert_eq!(transposed.as_slice(), &[0, 3, 1, 4, 2, 5]);
+
+        let ones = TypedArray::full(list![U1::new(), Dyn(3usize)], 1);
+        let sum = array.zip_with(&ones, |lhs, rhs| lhs + rhs);
+        assert_eq!(sum.dims(), vec![2, 3]);
+        assert_eq!(sum.as_slice(), &[1, 2, 3, 4, 5, 6]);
+
+        let cols = array.sum_axis::<U1>();
+        let _: &List![Dyn<usize>] = cols.shape();
+        assert_eq!(cols.as_slice(), &[3, 12]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn typed_array_dyn_bounds_test() {
+        let array = TypedArray::from_vec(list![Dyn(2usize)], vec![0, 1]).unwrap();
+        array.get::<List![U2]>();
+    }
+}
@@ -23,7 +23,6 @@ mod base {
     }
 
     impl BitSet for Nil {}
-
 }
 
 mod ops {
@@ -55,6 +54,36 @@ mod ops {
             }
         }
 
+        pub fn ToUnsigned<input>(input: BitSet) -> Unsigned {
+            match input {
+                #[generics(tail: BitSet)]
+                Cons::<B0, tail> => ToUnsigned(tail) * 2u,
+                #[generics(tail: BitSet)]
+                Cons::<B1, tail> => ToUnsigned(tail) * 2u + 1u,
+                Nil => 0u,
+            }
+        }
+
+        pub fn FromUnsigned<input, len>(input: Unsigned, len: Unsigned) -> BitSet {
+            if len == 0u {
+                Nil
+            } else {
+                let new_len: Unsigned = len - 1u;
+
+                match input {
+                    UTerm => {
+                        let tail = FromUnsigned(UTerm, new_len);
+                        Cons::<B0, tail>
+                    }
+                    #[generics(uint: Unsigned, bit: Bit)]
+                    UInt::<uint, bit> => {
+                        let tail = FromUnsigned(uint, new_len);
+                        Cons::<bit, tail>
+                    }
+                }
+            }
+        }
+
         pub fn Truncate<input, len>(input: BitSet, len: Unsigned) -> BitSet {
             TruncateRecursive(input, input, len)
         }
@@ -0,0 +1,112 @@
+//! Runtime indices that are proven to be below a typenum bound.
+
+use crate::{common::*, dyn_::Dyn};
+use typenum::IsLessOrEqual;
+
+/// A runtime index that is checked once at construction to be less than `N`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedIndex<N>
+where
+    N: Unsigned,
+{
+    index: usize,
+    _phantom: PhantomData<N>,
+}
+
+impl<N> BoundedIndex<N>
+where
+    N: Unsigned,
+{
+    /// Builds the index if it is less than `N`.
+    pub fn checked_new(index: usize) -> Option<Self> {
+        if index < N::USIZE {
+            Some(Self {
+                index,
+                _phantom: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over all indices from `0` to `N`, exclusive.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        (0..N::USIZE).map(|index| Self {
+            index,
+            _phantom: PhantomData,
+        })
+    }
+
+    pub fn get(self) -> usize {
+        self.index
+    }
+
+    /// Converts to an index with a bound `M` that is not less than `N`.
+    pub fn widen<M>(self) -> BoundedIndex<M>
+    where
+        M: Unsigned,
+        N: IsLessOrEqual<M, Output = B1>,
+    {
+        BoundedIndex {
+            index: self.index,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Converts to an index with the bound `M` if the index is less than `M`.
+    pub fn narrow<M>(self) -> Option<BoundedIndex<M>>
+    where
+        M: Unsigned,
+    {
+        BoundedIndex::checked_new(self.index)
+    }
+}
+
+impl<N> From<BoundedIndex<N>> for usize
+where
+    N: Unsigned,
+{
+    fn from(from: BoundedIndex<N>) -> Self {
+        from.index
+    }
+}
+
+impl<N> From<BoundedIndex<N>> for Dyn<usize>
+where
+    N: Unsigned,
+{
+    fn from(from: BoundedIndex<N>) -> Self {
+        Dyn(from.index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use typenum::consts::*;
+
+    #[test]
+    fn bounded_index_test() {
+        assert_eq!(
+            BoundedIndex::<U3>::checked_new(2).map(BoundedIndex::get),
+            Some(2)
+        );
+        assert_eq!(BoundedIndex::<U3>::checked_new(3), None);
+        assert_eq!(BoundedIndex::<U0>::checked_new(0), None);
+        assert_eq!(
+            BoundedIndex::<U3>::iter()
+                .map(usize::from)
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert_eq!(BoundedIndex::<U0>::iter().count(), 0);
+
+        let index = BoundedIndex::<U3>::checked_new(2).unwrap();
+        let widened: BoundedIndex<U5> = index.widen();
+        assert_eq!(widened.get(), 2);
+        let _: BoundedIndex<U3> = index.widen();
+        assert_eq!(widened.narrow::<U3>(), Some(index));
+        assert_eq!(widened.narrow::<U2>(), None);
+        assert_eq!(Dyn::from(index), Dyn(2));
+    }
+}
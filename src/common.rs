@@ -2,7 +2,7 @@ pub use std::{
     marker::PhantomData,
     ops::{
         Add, BitAnd, BitOr, BitXor, Div, Index, Mul, Neg, Not, Range, RangeFrom, RangeFull,
-        RangeInclusive, RangeTo, RangeToInclusive, Sub,
+        RangeInclusive, RangeTo, RangeToInclusive, Rem, Shl, Shr, Sub,
     },
 };
 pub use typ::{tyint, typ, tyuint};
@@ -1,3 +1,13 @@
+//! Control flow and static assertion type operators.
+
+use crate::{
+    common::*,
+    dyn_::{Dyn, ToUsize},
+};
+use typenum::{IsGreater, IsLess, IsLessOrEqual};
+
+// same
+
 pub trait Same<Lhs, Rhs, Out = ()> {
     type Output;
 }
@@ -8,3 +18,318 @@ impl<T, Out> Same<T, T, Out> for () {
 
 pub type SameOp<Lhs, Rhs> = <() as Same<Lhs, Rhs>>::Output;
 pub type SameExOp<Lhs, Rhs, Out> = <() as Same<Lhs, Rhs, Out>>::Output;
+
+// if
+
+/// Gives `Then` if `Cond` is [B1], or `Else` if `Cond` is [B0].
+///
+/// A `Dyn<bool>` condition is only known at runtime, and gives an [Either] of both branches.
+pub trait If<Cond, Then, Else> {
+    type Output;
+}
+
+pub type IfOp<Cond, Then, Else> = <() as If<Cond, Then, Else>>::Output;
+
+impl<Then, Else> If<B1, Then, Else> for () {
+    type Output = Then;
+}
+
+impl<Then, Else> If<B0, Then, Else> for () {
+    type Output = Else;
+}
+
+impl<Then, Else> If<Dyn<bool>, Then, Else> for () {
+    type Output = Either<Then, Else>;
+}
+
+/// Gives `Then` if `Lhs` is less than `Rhs`, or `Else` otherwise.
+pub trait IfLess<Lhs, Rhs, Then, Else> {
+    type Output;
+}
+
+pub type IfLessOp<Lhs, Rhs, Then, Else> = <() as IfLess<Lhs, Rhs, Then, Else>>::Output;
+
+impl<Lhs, Rhs, Then, Else> IfLess<Lhs, Rhs, Then, Else> for ()
+where
+    Lhs: IsLess<Rhs>,
+    (): If<<Lhs as IsLess<Rhs>>::Output, Then, Else>,
+{
+    type Output = IfOp<<Lhs as IsLess<Rhs>>::Output, Then, Else>;
+}
+
+/// Gives `Then` if `Lhs` is greater than `Rhs`, or `Else` otherwise.
+pub trait IfGreater<Lhs, Rhs, Then, Else> {
+    type Output;
+}
+
+pub type IfGreaterOp<Lhs, Rhs, Then, Else> = <() as IfGreater<Lhs, Rhs, Then, Else>>::Output;
+
+impl<Lhs, Rhs, Then, Else> IfGreater<Lhs, Rhs, Then, Else> for ()
+where
+    Lhs: IsGreater<Rhs>,
+    (): If<<Lhs as IsGreater<Rhs>>::Output, Then, Else>,
+{
+    type Output = IfOp<<Lhs as IsGreater<Rhs>>::Output, Then, Else>;
+}
+
+/// Evaluates the branch selected by a static or `Dyn<bool>` condition, matching the
+/// output type of [If].
+///
+/// [B1] and [B0] call one of the branches at compile time, while `Dyn<bool>` gives an
+/// [Either] of both branch types.
+pub trait IfValue<Then, Else> {
+    type Output;
+
+    fn if_else<ThenFn, ElseFn>(self, then: ThenFn, else_: ElseFn) -> Self::Output
+    where
+        ThenFn: FnOnce() -> Then,
+        ElseFn: FnOnce() -> Else;
+}
+
+impl<Then, Else> IfValue<Then, Else> for B1 {
+    type Output = Then;
+
+    fn if_else<ThenFn, ElseFn>(self, then: ThenFn, _else: ElseFn) -> Self::Output
+    where
+        ThenFn: FnOnce() -> Then,
+        ElseFn: FnOnce() -> Else,
+    {
+        then()
+    }
+}
+
+impl<Then, Else> IfValue<Then, Else> for B0 {
+    type Output = Else;
+
+    fn if_else<ThenFn, ElseFn>(self, _then: ThenFn, else_: ElseFn) -> Self::Output
+    where
+        ThenFn: FnOnce() -> Then,
+        ElseFn: FnOnce() -> Else,
+    {
+        else_()
+    }
+}
+
+impl<Then, Else> IfValue<Then, Else> for Dyn<bool> {
+    type Output = Either<Then, Else>;
+
+    fn if_else<ThenFn, ElseFn>(self, then: ThenFn, else_: ElseFn) -> Self::Output
+    where
+        ThenFn: FnOnce() -> Then,
+        ElseFn: FnOnce() -> Else,
+    {
+        if self.0 {
+            Either::Left(then())
+        } else {
+            Either::Right(else_())
+        }
+    }
+}
+
+// either
+
+/// The runtime result of [If] on a `Dyn<bool>` condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Either<Left, Right> {
+    Left(Left),
+    Right(Right),
+}
+
+impl<Left, Right> Either<Left, Right> {
+    pub fn map<NewLeft, NewRight, LeftFn, RightFn>(
+        self,
+        left: LeftFn,
+        right: RightFn,
+    ) -> Either<NewLeft, NewRight>
+    where
+        LeftFn: FnOnce(Left) -> NewLeft,
+        RightFn: FnOnce(Right) -> NewRight,
+    {
+        match self {
+            Self::Left(value) => Either::Left(left(value)),
+            Self::Right(value) => Either::Right(right(value)),
+        }
+    }
+
+    pub fn fold<Output, LeftFn, RightFn>(self, left: LeftFn, right: RightFn) -> Output
+    where
+        LeftFn: FnOnce(Left) -> Output,
+        RightFn: FnOnce(Right) -> Output,
+    {
+        match self {
+            Self::Left(value) => left(value),
+            Self::Right(value) => right(value),
+        }
+    }
+}
+
+impl<T> Either<T, T> {
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Left(value) | Self::Right(value) => value,
+        }
+    }
+}
+
+impl<Left, Right> ToUsize for Either<Left, Right>
+where
+    Left: ToUsize,
+    Right: ToUsize,
+{
+    fn to_usize(&self) -> usize {
+        match self {
+            Self::Left(value) => value.to_usize(),
+            Self::Right(value) => value.to_usize(),
+        }
+    }
+}
+
+// assert
+
+/// Gives `()` if `Cond` is [B1], and fails to compile otherwise.
+#[diagnostic::on_unimplemented(
+    message = "static assertion failed: condition `{Cond}` is not `B1`",
+    label = "assertion failed"
+)]
+pub trait AssertTrue<Cond> {
+    type Output;
+}
+
+pub type AssertTrueOp<Cond> = <() as AssertTrue<Cond>>::Output;
+
+impl AssertTrue<B1> for () {
+    type Output = ();
+}
+
+/// Gives `()` if `Lhs` and `Rhs` are the same type, and fails to compile otherwise.
+///
+/// Typenum integers are canonical, so it also asserts that two integers are equal.
+#[diagnostic::on_unimplemented(
+    message = "static assertion failed: `{Lhs}` and `{Rhs}` are not the same type",
+    label = "assertion failed"
+)]
+pub trait AssertSame<Lhs, Rhs> {
+    type Output;
+}
+
+pub type AssertSameOp<Lhs, Rhs> = <() as AssertSame<Lhs, Rhs>>::Output;
+
+impl<T> AssertSame<T, T> for () {
+    type Output = ();
+}
+
+/// Gives `()` if `Lhs` is less than `Rhs`, and fails to compile otherwise.
+#[diagnostic::on_unimplemented(
+    message = "static assertion failed: `{Lhs}` is not less than `{Rhs}`",
+    label = "assertion failed"
+)]
+pub trait AssertLess<Lhs, Rhs> {
+    type Output;
+}
+
+pub type AssertLessOp<Lhs, Rhs> = <() as AssertLess<Lhs, Rhs>>::Output;
+
+impl<Lhs, Rhs> AssertLess<Lhs, Rhs> for ()
+where
+    Lhs: IsLess<Rhs>,
+    (): AssertTrue<<Lhs as IsLess<Rhs>>::Output>,
+{
+    type Output = ();
+}
+
+/// Gives `()` if `Lhs` is less than or equal to `Rhs`, and fails to compile otherwise.
+#[diagnostic::on_unimplemented(
+    message = "static assertion failed: `{Lhs}` is greater than `{Rhs}`",
+    label = "assertion failed"
+)]
+pub trait AssertLessOrEqual<Lhs, Rhs> {
+    type Output;
+}
+
+pub type AssertLessOrEqualOp<Lhs, Rhs> = <() as AssertLessOrEqual<Lhs, Rhs>>::Output;
+
+impl<Lhs, Rhs> AssertLessOrEqual<Lhs, Rhs> for ()
+where
+    Lhs: IsLessOrEqual<Rhs>,
+    (): AssertTrue<<Lhs as IsLessOrEqual<Rhs>>::Output>,
+{
+    type Output = ();
+}
+
+// macros
+
+/// Asserts at compile time that each type [Bit] condition is [B1].
+///
+/// It is used in item position, and the compiler reports the failed condition.
+///
+/// ```rust
+/// use type_freak::static_assert;
+/// use typenum::{consts::*, Gr, Le};
+///
+/// static_assert!(Le<U3, U5>, Gr<U4, U2>);
+/// ```
+#[macro_export]
+macro_rules! static_assert {
+    ($($cond:ty),+ $(,)?) => {
+        $(
+            const _: $crate::control::AssertTrueOp<$cond> = ();
+        )+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{static_assert, List};
+    use typenum::{consts::*, Le};
+
+    static_assert!(B1, Le<U3, U5>);
+
+    #[test]
+    fn if_test() {
+        let _: SameOp<IfOp<B1, U1, U2>, U1> = ();
+        let _: SameOp<IfOp<B0, U1, U2>, U2> = ();
+        let _: SameOp<IfLessOp<U1, U2, U3, U4>, U3> = ();
+        let _: SameOp<IfLessOp<U2, U2, U3, U4>, U4> = ();
+        let _: SameOp<IfLessOp<N2, P1, U3, U4>, U3> = ();
+        let _: SameOp<IfGreaterOp<U3, U2, U3, U4>, U3> = ();
+        let _: SameOp<IfGreaterOp<U2, U2, U3, U4>, U4> = ();
+    }
+
+    #[test]
+    fn if_value_test() {
+        let _: SameOp<IfOp<Dyn<bool>, U1, U2>, Either<U1, U2>> = ();
+
+        let output: U3 = B1.if_else(U3::new, || "no");
+        assert_eq!(output, U3::new());
+        let output: &str = B0.if_else(U3::new, || "no");
+        assert_eq!(output, "no");
+
+        let cond = Dyn(4usize) > U3::new();
+        let output: Either<U3, Dyn<usize>> = Dyn(cond).if_else(U3::new, || Dyn(5));
+        assert_eq!(output, Either::Left(U3::new()));
+        assert_eq!(output.to_usize(), 3);
+
+        let output = (Dyn(false) | B0).if_else(U3::new, || Dyn(5usize));
+        assert_eq!(output, Either::Right(Dyn(5)));
+        assert_eq!(output.to_usize(), 5);
+        assert_eq!(
+            output
+                .map(|dim| dim.to_usize(), |dim| dim.0 + 1)
+                .into_inner(),
+            6
+        );
+        assert_eq!(output.fold(|_| "static", |_| "dynamic"), "dynamic");
+    }
+
+    #[test]
+    fn assert_test() {
+        let _: AssertTrueOp<B1> = ();
+        let _: AssertSameOp<U2, U2> = ();
+        let _: AssertSameOp<List![U1, U2], List![U1, U2]> = ();
+        let _: AssertLessOp<U1, U2> = ();
+        let _: AssertLessOp<N3, Z0> = ();
+        let _: AssertLessOrEqualOp<U2, U2> = ();
+        let _: AssertLessOrEqualOp<U1, U2> = ();
+    }
+}
@@ -1,3 +1,105 @@
-use crate::common::*;
+use super::{Dict, Nil};
+use crate::{
+    common::*,
+    list::{Cons, List},
+    maybe::{Just, Maybe, Nothing},
+    stepper::{Curr, Next, Stepper},
+};
 
-typ! {}
+typ! {
+    pub fn Insert<dict, key, value>(dict: Dict, key: _, value: _) -> Dict {
+        Cons::<(key, value), dict>
+    }
+
+    pub fn Get<dict, key, step>(dict: Dict, key: _, step: Stepper) {
+        match (dict, step) {
+            #[generics(value, tail: Dict)]
+            #[capture(key)]
+            (Cons::<(key, value), tail>, Curr) => value,
+            #[generics(head, tail: Dict, remaining: Stepper)]
+            (Cons::<head, tail>, Next::<remaining>) => Get(tail, key, remaining),
+        }
+    }
+
+    // Get selects the key by type identity, so its stepper is inferred by the compiler and
+    // cannot be named inside typ! functions. Typenum keys can be compared by value instead,
+    // which needs no stepper and gives Nothing for missing keys.
+    pub fn GetUnsigned<dict, key>(dict: Dict, key: Unsigned) -> Maybe {
+        match dict {
+            #[generics(curr_key: Unsigned, value, tail: Dict)]
+            Cons::<(curr_key, value), tail> => {
+                if curr_key == key {
+                    Just::<value>
+                } else {
+                    GetUnsigned(tail, key)
+                }
+            }
+            Nil => Nothing,
+        }
+    }
+
+    pub fn Remove<dict, key, step>(dict: Dict, key: _, step: Stepper) -> Dict {
+        match (dict, step) {
+            #[generics(value, tail: Dict)]
+            #[capture(key)]
+            (Cons::<(key, value), tail>, Curr) => tail,
+            #[generics(head, tail: Dict, remaining: Stepper)]
+            (Cons::<head, tail>, Next::<remaining>) => {
+                let new_tail = Remove(tail, key, remaining);
+                Cons::<head, new_tail>
+            }
+        }
+    }
+
+    pub fn Keys<dict>(dict: Dict) -> List {
+        match dict {
+            #[generics(key, value, tail: Dict)]
+            Cons::<(key, value), tail> => {
+                let new_tail = Keys(tail);
+                Cons::<key, new_tail>
+            }
+            Nil => Nil,
+        }
+    }
+
+    pub fn Values<dict>(dict: Dict) -> List {
+        match dict {
+            #[generics(key, value, tail: Dict)]
+            Cons::<(key, value), tail> => {
+                let new_tail = Values(tail);
+                Cons::<value, new_tail>
+            }
+            Nil => Nil,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{control::SameOp, Dict, List};
+    use typenum::consts::*;
+
+    struct Ka;
+    struct Kb;
+    struct Kc;
+    struct Va;
+    struct Vb;
+    struct Vc;
+
+    #[test]
+    fn dict_ops_test() {
+        let _: SameOp<InsertOp<Dict! {}, Ka, Va>, Dict! { Ka: Va }> = ();
+        let _: SameOp<InsertOp<Dict! { Kb: Vb }, Ka, Va>, Dict! { Ka: Va, Kb: Vb }> = ();
+        let _: SameOp<GetOp<Dict! { Ka: Va, Kb: Vb, Kc: Vc }, Ka, _>, Va> = ();
+        let _: SameOp<GetOp<Dict! { Ka: Va, Kb: Vb, Kc: Vc }, Kc, _>, Vc> = ();
+        let _: SameOp<GetUnsignedOp<Dict! { U1: Va, U3: Vb }, U3>, Just<Vb>> = ();
+        let _: SameOp<GetUnsignedOp<Dict! { U1: Va, U3: Vb }, U2>, Nothing> = ();
+        let _: SameOp<GetUnsignedOp<Dict! {}, U0>, Nothing> = ();
+        let _: SameOp<RemoveOp<Dict! { Ka: Va, Kb: Vb, Kc: Vc }, Kb, _>, Dict! { Ka: Va, Kc: Vc }> =
+            ();
+        let _: SameOp<KeysOp<Dict! { Ka: Va, Kb: Vb }>, List![Ka, Kb]> = ();
+        let _: SameOp<ValuesOp<Dict! { Ka: Va, Kb: Vb }>, List![Va, Vb]> = ();
+        let _: SameOp<KeysOp<Dict! {}>, List![]> = ();
+    }
+}
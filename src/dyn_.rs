@@ -1,425 +1,425 @@
-use crate::common::*;
+use crate::{
+    common::*,
+    floating::Float,
+    fraction::{NFrac, PFrac, UFrac, UFraction},
+    numeric::ToFloat,
+};
+use std::{cmp::Ordering, error::Error, fmt};
 
 // macros
 
 macro_rules! typenum_unsigned_impl {
+    (@op $ty:ty, $output:ident, $op:ident, $method:ident) => {
+        typenum_impl!(@op $ty, $output, $op, $method; [] UTerm);
+        typenum_impl!(@op $ty, $output, $op, $method; [U: Unsigned, B: Bit] UInt<U, B>);
+    };
+    (@cmp $ty:ty, $output:ident) => {
+        typenum_impl!(@cmp $ty, $output; [] UTerm);
+        typenum_impl!(@cmp $ty, $output; [U: Unsigned, B: Bit] UInt<U, B>);
+    };
     ($ty:ty, $output:ident) => {
-        impl Add<UTerm> for Dyn<$ty> {
-            type Output = Dyn<$ty>;
-
-            fn add(self, _rhs: UTerm) -> Self::Output {
-                Dyn(self.0.add(UTerm::$output))
-            }
-        }
-
-        impl Add<Dyn<$ty>> for UTerm {
-            type Output = Dyn<$ty>;
-
-            fn add(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.add(rhs.0))
-            }
-        }
-
-        impl<U, B> Add<UInt<U, B>> for Dyn<$ty>
-        where
-            U: Unsigned,
-            B: Bit,
-        {
-            type Output = Dyn<$ty>;
-
-            fn add(self, _rhs: UInt<U, B>) -> Self::Output {
-                Dyn(self.0.add(UInt::<U, B>::$output))
-            }
-        }
-
-        impl<U, B> Add<Dyn<$ty>> for UInt<U, B>
-        where
-            U: Unsigned,
-            B: Bit,
-        {
-            type Output = Dyn<$ty>;
-
-            fn add(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.add(rhs.0))
-            }
-        }
-
-        impl Sub<UTerm> for Dyn<$ty> {
-            type Output = Dyn<$ty>;
-
-            fn sub(self, _rhs: UTerm) -> Self::Output {
-                Dyn(self.0.sub(UTerm::$output))
-            }
-        }
-
-        impl Sub<Dyn<$ty>> for UTerm {
-            type Output = Dyn<$ty>;
-
-            fn sub(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.sub(rhs.0))
-            }
-        }
-
-        impl<U, B> Sub<UInt<U, B>> for Dyn<$ty>
-        where
-            U: Unsigned,
-            B: Bit,
-        {
-            type Output = Dyn<$ty>;
-
-            fn sub(self, _rhs: UInt<U, B>) -> Self::Output {
-                Dyn(self.0.sub(UInt::<U, B>::$output))
-            }
-        }
-
-        impl<U, B> Sub<Dyn<$ty>> for UInt<U, B>
-        where
-            U: Unsigned,
-            B: Bit,
-        {
-            type Output = Dyn<$ty>;
+        typenum_unsigned_impl!(@op $ty, $output, Add, add);
+        typenum_unsigned_impl!(@op $ty, $output, Sub, sub);
+        typenum_unsigned_impl!(@op $ty, $output, Mul, mul);
+        typenum_unsigned_impl!(@op $ty, $output, Div, div);
+        typenum_unsigned_impl!(@op $ty, $output, Rem, rem);
+        typenum_unsigned_impl!(@op $ty, $output, Shl, shl);
+        typenum_unsigned_impl!(@op $ty, $output, Shr, shr);
+        typenum_unsigned_impl!(@op $ty, $output, BitAnd, bitand);
+        typenum_unsigned_impl!(@op $ty, $output, BitOr, bitor);
+        typenum_unsigned_impl!(@op $ty, $output, BitXor, bitxor);
+        typenum_unsigned_impl!(@op $ty, $output, Min, min);
+        typenum_unsigned_impl!(@op $ty, $output, Max, max);
+        typenum_unsigned_impl!(@cmp $ty, $output);
+    };
+}
 
-            fn sub(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.sub(rhs.0))
-            }
-        }
+macro_rules! typenum_signed_impl {
+    (@op $ty:ty, $output:ident, $op:ident, $method:ident) => {
+        typenum_impl!(@op $ty, $output, $op, $method; [] Z0);
+        typenum_impl!(@op $ty, $output, $op, $method; [U: Unsigned + NonZero] PInt<U>);
+        typenum_impl!(@op $ty, $output, $op, $method; [U: Unsigned + NonZero] NInt<U>);
+    };
+    (@shift $ty:ty, $op:ident, $method:ident) => {
+        typenum_impl!(@shift $ty, $op, $method, U32; [] UTerm);
+        typenum_impl!(@shift $ty, $op, $method, U32; [U: Unsigned, B: Bit] UInt<U, B>);
+        typenum_impl!(@shift $ty, $op, $method, I32; [] Z0);
+        typenum_impl!(@shift $ty, $op, $method, I32; [U: Unsigned + NonZero] PInt<U>);
+    };
+    (@cmp $ty:ty, $output:ident) => {
+        typenum_impl!(@cmp $ty, $output; [] Z0);
+        typenum_impl!(@cmp $ty, $output; [U: Unsigned + NonZero] PInt<U>);
+        typenum_impl!(@cmp $ty, $output; [U: Unsigned + NonZero] NInt<U>);
+    };
+    ($ty:ty, $output:ident) => {
+        typenum_signed_impl!(@op $ty, $output, Add, add);
+        typenum_signed_impl!(@op $ty, $output, Sub, sub);
+        typenum_signed_impl!(@op $ty, $output, Mul, mul);
+        typenum_signed_impl!(@op $ty, $output, Div, div);
+        typenum_signed_impl!(@op $ty, $output, Rem, rem);
+        typenum_signed_impl!(@shift $ty, Shl, shl);
+        typenum_signed_impl!(@shift $ty, Shr, shr);
+        typenum_signed_impl!(@op $ty, $output, BitAnd, bitand);
+        typenum_signed_impl!(@op $ty, $output, BitOr, bitor);
+        typenum_signed_impl!(@op $ty, $output, BitXor, bitxor);
+        typenum_signed_impl!(@op $ty, $output, Min, min);
+        typenum_signed_impl!(@op $ty, $output, Max, max);
+        typenum_signed_impl!(@cmp $ty, $output);
+    };
+}
 
-        impl Mul<UTerm> for Dyn<$ty> {
+macro_rules! typenum_impl {
+    (@op $ty:ty, $output:ident, $op:ident, $method:ident; [$($generics:tt)*] $typenum:ty) => {
+        impl<$($generics)*> $op<$typenum> for Dyn<$ty> {
             type Output = Dyn<$ty>;
 
-            fn mul(self, _rhs: UTerm) -> Self::Output {
-                Dyn(self.0.mul(UTerm::$output))
+            fn $method(self, _rhs: $typenum) -> Self::Output {
+                Dyn(dyn_op!($op, $method, self.0, <$typenum>::$output))
             }
         }
 
-        impl Mul<Dyn<$ty>> for UTerm {
+        impl<$($generics)*> $op<Dyn<$ty>> for $typenum {
             type Output = Dyn<$ty>;
 
-            fn mul(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.mul(rhs.0))
+            fn $method(self, rhs: Dyn<$ty>) -> Self::Output {
+                Dyn(dyn_op!($op, $method, Self::$output, rhs.0))
             }
         }
-
-        impl<U, B> Mul<UInt<U, B>> for Dyn<$ty>
-        where
-            U: Unsigned,
-            B: Bit,
-        {
+    };
+    (@shift $ty:ty, $op:ident, $method:ident, $amount:ident; [$($generics:tt)*] $typenum:ty) => {
+        impl<$($generics)*> $op<$typenum> for Dyn<$ty> {
             type Output = Dyn<$ty>;
 
-            fn mul(self, _rhs: UInt<U, B>) -> Self::Output {
-                Dyn(self.0.mul(UInt::<U, B>::$output))
+            fn $method(self, _rhs: $typenum) -> Self::Output {
+                Dyn($op::$method(self.0, <$typenum>::$amount))
             }
         }
-
-        impl<U, B> Mul<Dyn<$ty>> for UInt<U, B>
-        where
-            U: Unsigned,
-            B: Bit,
-        {
-            type Output = Dyn<$ty>;
-
-            fn mul(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.mul(rhs.0))
+    };
+    (@cmp $ty:ty, $output:ident; [$($generics:tt)*] $typenum:ty) => {
+        impl<$($generics)*> PartialEq<$typenum> for Dyn<$ty> {
+            fn eq(&self, _other: &$typenum) -> bool {
+                self.0 == <$typenum>::$output
             }
         }
 
-        impl Div<UTerm> for Dyn<$ty> {
-            type Output = Dyn<$ty>;
-
-            fn div(self, _rhs: UTerm) -> Self::Output {
-                Dyn(self.0.div(UTerm::$output))
+        impl<$($generics)*> PartialEq<Dyn<$ty>> for $typenum {
+            fn eq(&self, other: &Dyn<$ty>) -> bool {
+                Self::$output == other.0
             }
         }
 
-        impl Div<Dyn<$ty>> for UTerm {
-            type Output = Dyn<$ty>;
-
-            fn div(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.div(rhs.0))
+        impl<$($generics)*> PartialOrd<$typenum> for Dyn<$ty> {
+            fn partial_cmp(&self, _other: &$typenum) -> Option<Ordering> {
+                self.0.partial_cmp(&<$typenum>::$output)
             }
         }
 
-        impl<U, B> Div<UInt<U, B>> for Dyn<$ty>
-        where
-            U: Unsigned,
-            B: Bit,
-        {
-            type Output = Dyn<$ty>;
-
-            fn div(self, _rhs: UInt<U, B>) -> Self::Output {
-                Dyn(self.0.div(UInt::<U, B>::$output))
-            }
-        }
-
-        impl<U, B> Div<Dyn<$ty>> for UInt<U, B>
-        where
-            U: Unsigned,
-            B: Bit,
-        {
-            type Output = Dyn<$ty>;
-
-            fn div(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.div(rhs.0))
+        impl<$($generics)*> PartialOrd<Dyn<$ty>> for $typenum {
+            fn partial_cmp(&self, other: &Dyn<$ty>) -> Option<Ordering> {
+                Self::$output.partial_cmp(&other.0)
             }
         }
     };
 }
 
-macro_rules! typenum_signed_impl {
-    ($ty:ty, $output:ident) => {
-        impl<U> Add<PInt<U>> for Dyn<$ty>
-        where
-            U: Unsigned + NonZero,
-        {
-            type Output = Dyn<$ty>;
-
-            fn add(self, _rhs: PInt<U>) -> Self::Output {
-                Dyn(self.0.add(PInt::<U>::$output))
-            }
-        }
-
-        impl<U> Add<Dyn<$ty>> for PInt<U>
-        where
-            U: Unsigned + NonZero,
-        {
-            type Output = Dyn<$ty>;
-
-            fn add(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.add(rhs.0))
-            }
-        }
-
-        impl<U> Add<NInt<U>> for Dyn<$ty>
-        where
-            U: Unsigned + NonZero,
-        {
-            type Output = Dyn<$ty>;
-
-            fn add(self, _rhs: NInt<U>) -> Self::Output {
-                Dyn(self.0.add(NInt::<U>::$output))
-            }
-        }
-
-        impl<U> Add<Dyn<$ty>> for NInt<U>
+macro_rules! float_impl {
+    (@op $ty:ty, $to_float:ident, $op:ident, $method:ident) => {
+        impl<T> $op<T> for Dyn<$ty>
         where
-            U: Unsigned + NonZero,
+            T: ToFloat,
         {
             type Output = Dyn<$ty>;
 
-            fn add(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.add(rhs.0))
+            fn $method(self, _rhs: T) -> Self::Output {
+                Dyn(self.0.$method(T::$to_float()))
             }
         }
+    };
+    (@rev $ty:ty, $to_float:ident; [$($generics:tt)*] $value:ty) => {
+        float_impl!(@rev $ty, $to_float, Add, add; [$($generics)*] $value);
+        float_impl!(@rev $ty, $to_float, Sub, sub; [$($generics)*] $value);
+        float_impl!(@rev $ty, $to_float, Mul, mul; [$($generics)*] $value);
+        float_impl!(@rev $ty, $to_float, Div, div; [$($generics)*] $value);
+        float_impl!(@rev $ty, $to_float, Rem, rem; [$($generics)*] $value);
 
-        impl Add<Z0> for Dyn<$ty> {
-            type Output = Dyn<$ty>;
-
-            fn add(self, _rhs: Z0) -> Self::Output {
-                Dyn(self.0.add(Z0::$output))
+        impl<$($generics)*> PartialEq<Dyn<$ty>> for $value {
+            fn eq(&self, other: &Dyn<$ty>) -> bool {
+                <$value as ToFloat>::$to_float() == other.0
             }
         }
 
-        impl Add<Dyn<$ty>> for Z0 {
-            type Output = Dyn<$ty>;
-
-            fn add(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.add(rhs.0))
+        impl<$($generics)*> PartialOrd<Dyn<$ty>> for $value {
+            fn partial_cmp(&self, other: &Dyn<$ty>) -> Option<Ordering> {
+                <$value as ToFloat>::$to_float().partial_cmp(&other.0)
             }
         }
-
-        impl<U> Sub<PInt<U>> for Dyn<$ty>
-        where
-            U: Unsigned + NonZero,
-        {
-            type Output = Dyn<$ty>;
-
-            fn sub(self, _rhs: PInt<U>) -> Self::Output {
-                Dyn(self.0.sub(PInt::<U>::$output))
-            }
-        }
-
-        impl<U> Sub<Dyn<$ty>> for PInt<U>
-        where
-            U: Unsigned + NonZero,
-        {
+    };
+    (@rev $ty:ty, $to_float:ident, $op:ident, $method:ident; [$($generics:tt)*] $value:ty) => {
+        impl<$($generics)*> $op<Dyn<$ty>> for $value {
             type Output = Dyn<$ty>;
 
-            fn sub(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.sub(rhs.0))
+            fn $method(self, rhs: Dyn<$ty>) -> Self::Output {
+                Dyn(<$value as ToFloat>::$to_float().$method(rhs.0))
             }
         }
-
-        impl<U> Sub<NInt<U>> for Dyn<$ty>
+    };
+    ($ty:ty, $to_float:ident) => {
+        float_impl!(@op $ty, $to_float, Add, add);
+        float_impl!(@op $ty, $to_float, Sub, sub);
+        float_impl!(@op $ty, $to_float, Mul, mul);
+        float_impl!(@op $ty, $to_float, Div, div);
+        float_impl!(@op $ty, $to_float, Rem, rem);
+
+        impl<T> PartialEq<T> for Dyn<$ty>
         where
-            U: Unsigned + NonZero,
+            T: ToFloat,
         {
-            type Output = Dyn<$ty>;
-
-            fn sub(self, _rhs: NInt<U>) -> Self::Output {
-                Dyn(self.0.sub(NInt::<U>::$output))
+            fn eq(&self, _other: &T) -> bool {
+                self.0 == T::$to_float()
             }
         }
 
-        impl<U> Sub<Dyn<$ty>> for NInt<U>
+        impl<T> PartialOrd<T> for Dyn<$ty>
         where
-            U: Unsigned + NonZero,
+            T: ToFloat,
         {
-            type Output = Dyn<$ty>;
-
-            fn sub(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.sub(rhs.0))
-            }
-        }
-
-        impl Sub<Z0> for Dyn<$ty> {
-            type Output = Dyn<$ty>;
+            fn partial_cmp(&self, _other: &T) -> Option<Ordering> {
+                self.0.partial_cmp(&T::$to_float())
+            }
+        }
+
+        float_impl!(@rev $ty, $to_float; [] UTerm);
+        float_impl!(@rev $ty, $to_float; [U: Unsigned, B: Bit] UInt<U, B>);
+        float_impl!(@rev $ty, $to_float; [] Z0);
+        float_impl!(@rev $ty, $to_float; [U: Unsigned + NonZero] PInt<U>);
+        float_impl!(@rev $ty, $to_float; [U: Unsigned + NonZero] NInt<U>);
+        float_impl!(@rev $ty, $to_float; [N: Unsigned, D: Unsigned + NonZero] UFrac<N, D>);
+        float_impl!(@rev $ty, $to_float; [F: UFraction + ToFloat] PFrac<F>);
+        float_impl!(@rev $ty, $to_float; [F: UFraction + ToFloat] NFrac<F>);
+        float_impl!(
+            @rev $ty, $to_float;
+            [B: Unsigned + NonZero, S: Integer, E: Integer] Float<B, S, E>
+        );
+    };
+}
 
-            fn sub(self, _rhs: Z0) -> Self::Output {
-                Dyn(self.0.sub(Z0::$output))
-            }
-        }
+/// Applies the std or typenum operator on primitive values.
+macro_rules! dyn_op {
+    (Min, $method:ident, $lhs:expr, $rhs:expr) => {
+        Ord::min($lhs, $rhs)
+    };
+    (Max, $method:ident, $lhs:expr, $rhs:expr) => {
+        Ord::max($lhs, $rhs)
+    };
+    ($op:ident, $method:ident, $lhs:expr, $rhs:expr) => {
+        $op::$method($lhs, $rhs)
+    };
+}
 
-        impl Sub<Dyn<$ty>> for Z0 {
-            type Output = Dyn<$ty>;
+// type
 
-            fn sub(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.sub(rhs.0))
-            }
-        }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dyn<T>(pub T);
 
-        impl<U> Mul<PInt<U>> for Dyn<$ty>
-        where
-            U: Unsigned + NonZero,
-        {
-            type Output = Dyn<$ty>;
+impl<T> Dyn<T> {
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+}
 
-            fn mul(self, _rhs: PInt<U>) -> Self::Output {
-                Dyn(self.0.mul(PInt::<U>::$output))
-            }
-        }
+// runtime values
 
-        impl<U> Mul<Dyn<$ty>> for PInt<U>
-        where
-            U: Unsigned + NonZero,
-        {
-            type Output = Dyn<$ty>;
+/// Reads a static [Unsigned] or a `Dyn<usize>` as a runtime `usize`.
+pub trait ToUsize {
+    fn to_usize(&self) -> usize;
+}
 
-            fn mul(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.mul(rhs.0))
-            }
-        }
+impl ToUsize for Dyn<usize> {
+    fn to_usize(&self) -> usize {
+        self.0
+    }
+}
 
-        impl<U> Mul<NInt<U>> for Dyn<$ty>
-        where
-            U: Unsigned + NonZero,
-        {
-            type Output = Dyn<$ty>;
+impl ToUsize for UTerm {
+    fn to_usize(&self) -> usize {
+        Self::USIZE
+    }
+}
 
-            fn mul(self, _rhs: NInt<U>) -> Self::Output {
-                Dyn(self.0.mul(NInt::<U>::$output))
-            }
-        }
+impl<U, B> ToUsize for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+{
+    fn to_usize(&self) -> usize {
+        Self::USIZE
+    }
+}
 
-        impl<U> Mul<Dyn<$ty>> for NInt<U>
-        where
-            U: Unsigned + NonZero,
-        {
-            type Output = Dyn<$ty>;
+// static values
 
-            fn mul(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.mul(rhs.0))
-            }
-        }
+/// The runtime value of a typenum integer or bit, which is `None` if the value is out of
+/// the range of `T`.
+pub trait StaticValue<T> {
+    const VALUE: Option<T>;
+}
 
-        impl Mul<Z0> for Dyn<$ty> {
-            type Output = Dyn<$ty>;
+macro_rules! static_value_impl {
+    (Unsigned; $($ty:ty),*) => {
+        $(
+            impl<Value> StaticValue<$ty> for Value
+            where
+                Value: Unsigned,
+            {
+                const VALUE: Option<$ty> = if Value::U128 <= <$ty>::MAX as u128 {
+                    Some(Value::U128 as $ty)
+                } else {
+                    None
+                };
+            }
+        )*
+    };
+    (Integer; $($ty:ty),*) => {
+        $(
+            impl<Value> StaticValue<$ty> for Value
+            where
+                Value: Integer,
+            {
+                const VALUE: Option<$ty> =
+                    if Value::I128 >= <$ty>::MIN as i128 && Value::I128 <= <$ty>::MAX as i128 {
+                        Some(Value::I128 as $ty)
+                    } else {
+                        None
+                    };
+            }
+        )*
+    };
+}
 
-            fn mul(self, _rhs: Z0) -> Self::Output {
-                Dyn(self.0.mul(Z0::$output))
-            }
-        }
+static_value_impl!(Unsigned; u8, u16, u32, u64, usize);
+static_value_impl!(Integer; i8, i16, i32, i64, isize);
 
-        impl Mul<Dyn<$ty>> for Z0 {
-            type Output = Dyn<$ty>;
+impl<Value> StaticValue<u128> for Value
+where
+    Value: Unsigned,
+{
+    const VALUE: Option<u128> = Some(Value::U128);
+}
 
-            fn mul(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.mul(rhs.0))
-            }
-        }
+impl<Value> StaticValue<i128> for Value
+where
+    Value: Integer,
+{
+    const VALUE: Option<i128> = Some(Value::I128);
+}
 
-        impl<U> Div<PInt<U>> for Dyn<$ty>
-        where
-            U: Unsigned + NonZero,
-        {
-            type Output = Dyn<$ty>;
+impl<Value> StaticValue<bool> for Value
+where
+    Value: Bit,
+{
+    const VALUE: Option<bool> = Some(Value::BOOL);
+}
 
-            fn div(self, _rhs: PInt<U>) -> Self::Output {
-                Dyn(self.0.div(PInt::<U>::$output))
-            }
-        }
+/// The error when a dynamic value differs from the static value it is promoted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mismatch<T> {
+    pub expected: T,
+    pub actual: T,
+}
 
-        impl<U> Div<Dyn<$ty>> for PInt<U>
-        where
-            U: Unsigned + NonZero,
-        {
-            type Output = Dyn<$ty>;
+impl<T> fmt::Display for Mismatch<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {:?}, found {:?}", self.expected, self.actual)
+    }
+}
 
-            fn div(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.div(rhs.0))
-            }
-        }
+impl<T> Error for Mismatch<T> where T: fmt::Debug {}
 
-        impl<U> Div<NInt<U>> for Dyn<$ty>
-        where
-            U: Unsigned + NonZero,
-        {
-            type Output = Dyn<$ty>;
+/// The error when a dynamic value cannot be promoted to a static value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntoStaticError<T> {
+    /// The values differ.
+    Mismatch(Mismatch<T>),
+    /// The static value is out of the range of `T`, so that no dynamic value matches it.
+    OutOfRange { actual: T },
+}
 
-            fn div(self, _rhs: NInt<U>) -> Self::Output {
-                Dyn(self.0.div(NInt::<U>::$output))
-            }
+impl<T> fmt::Display for IntoStaticError<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mismatch(mismatch) => fmt::Display::fmt(mismatch, f),
+            Self::OutOfRange { actual } => write!(
+                f,
+                "expected a value out of the range of the type, found {:?}",
+                actual
+            ),
         }
+    }
+}
 
-        impl<U> Div<Dyn<$ty>> for NInt<U>
-        where
-            U: Unsigned + NonZero,
-        {
-            type Output = Dyn<$ty>;
+impl<T> Error for IntoStaticError<T> where T: fmt::Debug {}
 
-            fn div(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.div(rhs.0))
-            }
+impl<T> Dyn<T>
+where
+    T: PartialEq,
+{
+    /// Promotes the value to the static type `U` if their values are equal.
+    pub fn try_into_static<U>(self) -> Result<U, IntoStaticError<T>>
+    where
+        U: StaticValue<T> + Default,
+    {
+        match U::VALUE {
+            Some(expected) if self.0 == expected => Ok(U::default()),
+            Some(expected) => Err(IntoStaticError::Mismatch(Mismatch {
+                expected,
+                actual: self.0,
+            })),
+            None => Err(IntoStaticError::OutOfRange { actual: self.0 }),
         }
+    }
+}
 
-        impl Div<Z0> for Dyn<$ty> {
-            type Output = Dyn<$ty>;
-
-            fn div(self, _rhs: Z0) -> Self::Output {
-                Dyn(self.0.div(Z0::$output))
-            }
-        }
+/// Converts a static or dynamic item to the static `Target`, where `T` is the type of
+/// dynamic values.
+///
+/// Static items only convert to themselves, and never fail.
+pub trait TryIntoStatic<Target, T> {
+    fn try_into_static(self) -> Result<Target, IntoStaticError<T>>;
+}
 
-        impl Div<Dyn<$ty>> for Z0 {
-            type Output = Dyn<$ty>;
+impl<Target, T> TryIntoStatic<Target, T> for Dyn<T>
+where
+    T: PartialEq,
+    Target: StaticValue<T> + Default,
+{
+    fn try_into_static(self) -> Result<Target, IntoStaticError<T>> {
+        Dyn::try_into_static(self)
+    }
+}
 
-            fn div(self, rhs: Dyn<$ty>) -> Self::Output {
-                Dyn(Self::$output.div(rhs.0))
+macro_rules! try_into_static_impl {
+    ([$($generics:tt)*] $ty:ty) => {
+        impl<T, $($generics)*> TryIntoStatic<$ty, T> for $ty {
+            fn try_into_static(self) -> Result<$ty, IntoStaticError<T>> {
+                Ok(self)
             }
         }
     };
 }
 
-// type
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Dyn<T>(pub T);
-
-impl<T> Dyn<T> {
-    pub fn new(value: T) -> Self {
-        Self(value)
-    }
-}
+try_into_static_impl!([] UTerm);
+try_into_static_impl!([U, B] UInt<U, B>);
+try_into_static_impl!([] Z0);
+try_into_static_impl!([U] PInt<U>);
+try_into_static_impl!([U] NInt<U>);
+try_into_static_impl!([] B0);
+try_into_static_impl!([] B1);
 
 // general impls
 
@@ -500,6 +500,83 @@ where
     }
 }
 
+impl<T> Rem<Dyn<T>> for Dyn<T>
+where
+    T: Rem<T>,
+{
+    type Output = Dyn<<T as Rem<T>>::Output>;
+
+    fn rem(self, rhs: Dyn<T>) -> Self::Output {
+        Dyn(self.0.rem(rhs.0))
+    }
+}
+
+impl<T> Shl<Dyn<T>> for Dyn<T>
+where
+    T: Shl<T>,
+{
+    type Output = Dyn<<T as Shl<T>>::Output>;
+
+    fn shl(self, rhs: Dyn<T>) -> Self::Output {
+        Dyn(self.0.shl(rhs.0))
+    }
+}
+
+impl<T> Shr<Dyn<T>> for Dyn<T>
+where
+    T: Shr<T>,
+{
+    type Output = Dyn<<T as Shr<T>>::Output>;
+
+    fn shr(self, rhs: Dyn<T>) -> Self::Output {
+        Dyn(self.0.shr(rhs.0))
+    }
+}
+
+impl<T> BitXor<Dyn<T>> for Dyn<T>
+where
+    T: BitXor<T>,
+{
+    type Output = Dyn<<T as BitXor<T>>::Output>;
+
+    fn bitxor(self, rhs: Dyn<T>) -> Self::Output {
+        Dyn(self.0.bitxor(rhs.0))
+    }
+}
+
+impl<T> Neg for Dyn<T>
+where
+    T: Neg,
+{
+    type Output = Dyn<<T as Neg>::Output>;
+
+    fn neg(self) -> Self::Output {
+        Dyn(self.0.neg())
+    }
+}
+
+impl<T> Min<Dyn<T>> for Dyn<T>
+where
+    T: Ord,
+{
+    type Output = Dyn<T>;
+
+    fn min(self, rhs: Dyn<T>) -> Self::Output {
+        Dyn(Ord::min(self.0, rhs.0))
+    }
+}
+
+impl<T> Max<Dyn<T>> for Dyn<T>
+where
+    T: Ord,
+{
+    type Output = Dyn<T>;
+
+    fn max(self, rhs: Dyn<T>) -> Self::Output {
+        Dyn(Ord::max(self.0, rhs.0))
+    }
+}
+
 // impls for typenum ops
 
 typenum_unsigned_impl!(usize, USIZE);
@@ -507,12 +584,19 @@ typenum_unsigned_impl!(u8, U8);
 typenum_unsigned_impl!(u16, U16);
 typenum_unsigned_impl!(u32, U32);
 typenum_unsigned_impl!(u64, U64);
+typenum_unsigned_impl!(u128, U128);
 
 typenum_signed_impl!(isize, ISIZE);
 typenum_signed_impl!(i8, I8);
 typenum_signed_impl!(i16, I16);
 typenum_signed_impl!(i32, I32);
 typenum_signed_impl!(i64, I64);
+typenum_signed_impl!(i128, I128);
+
+// impls for float ops
+
+float_impl!(f32, to_f32);
+float_impl!(f64, to_f64);
 
 // impls for bool ops
 
@@ -560,7 +644,7 @@ impl BitOr<B0> for Dyn<bool> {
     type Output = Dyn<bool>;
 
     fn bitor(self, _rhs: B0) -> Self::Output {
-        Dyn(self.0.bitor(B1::BOOL))
+        Dyn(self.0.bitor(B0::BOOL))
     }
 }
 
@@ -579,3 +663,82 @@ impl BitOr<Dyn<bool>> for B0 {
         Dyn(B0::BOOL.bitor(rhs.0))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fraction::Fraction, Float, Frac, UFrac};
+    use typenum::consts::*;
+
+    #[test]
+    fn dyn_int_test() {
+        assert_eq!(Dyn(7usize) % U4::new(), Dyn(3));
+        assert_eq!(U7::new() % Dyn(4usize), Dyn(3));
+        assert_eq!(Dyn(3u8) << U2::new(), Dyn(12));
+        assert_eq!(Dyn(12u8) >> U2::new(), Dyn(3));
+        assert_eq!(Dyn(6u32) ^ U3::new(), Dyn(5));
+        assert_eq!(Dyn(6u32) & U3::new(), Dyn(2));
+        assert_eq!(Dyn(6u32) | U3::new(), Dyn(7));
+        assert_eq!(-Dyn(3i32), Dyn(-3));
+        assert_eq!(Dyn(-7i64) % P4::new(), Dyn(-3));
+        assert_eq!(Dyn(-3i32) << U3::new(), Dyn(-24));
+        assert_eq!(Dyn(-24i64) >> P2::new(), Dyn(-6));
+        assert_eq!(Dyn(5i8) << Z0::new(), Dyn(5));
+        assert_eq!(Dyn(6i32) ^ P5::new(), Dyn(3));
+        assert_eq!(Dyn(-6i32) & P3::new(), Dyn(2));
+        assert_eq!(N6::new() | Dyn(3i16), Dyn(-5));
+        assert_eq!(Min::min(Dyn(7usize), U4::new()), Dyn(4));
+        assert_eq!(Max::max(U4::new(), Dyn(7usize)), Dyn(7));
+        assert_eq!(Max::max(Dyn(-7i8), N2::new()), Dyn(-2));
+        assert_eq!(Max::max(Dyn(3u16), Dyn(5u16)), Dyn(5));
+        assert_eq!(Dyn(1u128 << 100) + U1::new(), Dyn((1u128 << 100) + 1));
+        assert_eq!(Dyn(-5i128) * N2::new(), Dyn(10));
+
+        assert!(Dyn(4usize) == U4::new());
+        assert!(U4::new() == Dyn(4usize));
+        assert!(Dyn(3usize) < U4::new());
+        assert!(N2::new() < Dyn(0i32));
+        assert!(Dyn(0i32) == Z0::new());
+    }
+
+    #[test]
+    fn dyn_float_test() {
+        assert_eq!(Dyn(1.0f64) + U2::new(), Dyn(3.0f64));
+        assert_eq!(Dyn(1.0f32) - N2::new(), Dyn(3.0f32));
+        assert_eq!(Dyn(1.0f64) * <Frac!(-3 / 4)>::new(), Dyn(-0.75f64));
+        assert_eq!(<UFrac!(1 / 2)>::new() + Dyn(0.25f64), Dyn(0.75f64));
+        assert_eq!(Dyn(2.0f64) / <Float!(1.25e-1)>::new(), Dyn(16.0f64));
+
+        assert!(Dyn(0.5f64) == <UFrac!(1 / 2)>::new());
+        assert!(Dyn(0.5f32) < <Frac!(3 / 4)>::new());
+        assert!(<Float!(-1.5)>::new() < Dyn(0.0f64));
+        assert!(Dyn(3.0f64) > P2::new());
+    }
+
+    #[test]
+    fn try_into_static_test() {
+        let _: U3 = Dyn(3usize).try_into_static().unwrap();
+        let _: N2 = Dyn(-2i32).try_into_static().unwrap();
+        let _: B1 = Dyn(true).try_into_static().unwrap();
+        assert_eq!(
+            Dyn(4usize).try_into_static::<U3>(),
+            Err(IntoStaticError::Mismatch(Mismatch {
+                expected: 3,
+                actual: 4
+            }))
+        );
+        assert_eq!(
+            Dyn(44u8).try_into_static::<U300>(),
+            Err(IntoStaticError::OutOfRange { actual: 44 })
+        );
+        assert_eq!(Dyn(-128i8).try_into_static::<N128>(), Ok(N128::new()));
+        assert_eq!(
+            Dyn(127i8).try_into_static::<N129>(),
+            Err(IntoStaticError::OutOfRange { actual: 127 })
+        );
+        assert_eq!(
+            TryIntoStatic::<U3, usize>::try_into_static(U3::new()),
+            Ok(U3::new())
+        );
+    }
+}
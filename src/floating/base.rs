@@ -1,4 +1,4 @@
-use crate::common::*;
+use crate::{common::*, numeric::ToFloat};
 use typenum::consts::*;
 
 pub trait Floating {}
@@ -9,6 +9,17 @@ where
     Significant: Integer,
     Exponent: Integer;
 
+impl<Base, Significant, Exponent> Float<Base, Significant, Exponent>
+where
+    Base: Unsigned + NonZero,
+    Significant: Integer,
+    Exponent: Integer,
+{
+    pub fn new() -> Self {
+        Self(Base::default(), Significant::default(), Exponent::default())
+    }
+}
+
 impl<Significant, Base, Exponent> Floating for Float<Base, Significant, Exponent>
 where
     Base: Unsigned + NonZero,
@@ -17,4 +28,78 @@ where
 {
 }
 
+impl<Base, Significant, Exponent> ToFloat for Float<Base, Significant, Exponent>
+where
+    Base: Unsigned + NonZero,
+    Significant: Integer,
+    Exponent: Integer,
+{
+    fn to_f64() -> f64 {
+        // decimal floats go through the correctly rounded float parser of std
+        if Base::U128 == 10 {
+            return format!("{}e{}", Significant::I128, Exponent::I128)
+                .parse()
+                .unwrap();
+        }
+
+        let base = Base::U128 as f64;
+        let mut value = Significant::I128 as f64;
+        let mut exp = Exponent::I128;
+        if Base::U128 == 1 {
+            return value;
+        }
+
+        // scale by powers of at most 2^1022, so that large exponents saturate instead of
+        // overflowing early. It is exact in base 2 for significands of at most 53 bits,
+        // while other bases round on every step.
+        let chunk = ((f64::MAX_EXP - 2) as f64 / base.log2()).max(1.0) as i128;
+        while exp > 0 && value.is_finite() && value != 0.0 {
+            let step = exp.min(chunk);
+            value *= base.powi(step as i32);
+            exp -= step;
+        }
+        while exp < 0 && value != 0.0 {
+            let step = (-exp).min(chunk);
+            value /= base.powi(step as i32);
+            exp += step;
+        }
+
+        value
+    }
+
+    fn to_f32() -> f32 {
+        if Base::U128 == 10 {
+            return format!("{}e{}", Significant::I128, Exponent::I128)
+                .parse()
+                .unwrap();
+        }
+
+        Self::to_f64() as f32
+    }
+}
+
 pub type Float2<Significant, Exponent> = Float<U2, Significant, Exponent>;
+
+/// The output of float operators when the result cannot be exactly represented in the base.
+pub struct NotRepresentable;
+
+// rounding modes
+
+pub trait RoundingMode {}
+
+/// Rounds to the nearest value, and to the even significand on ties.
+pub struct RoundNearestEven;
+
+/// Rounds toward zero.
+pub struct RoundTowardZero;
+
+/// Rounds toward positive infinity.
+pub struct RoundUp;
+
+/// Rounds toward negative infinity.
+pub struct RoundDown;
+
+impl RoundingMode for RoundNearestEven {}
+impl RoundingMode for RoundTowardZero {}
+impl RoundingMode for RoundUp {}
+impl RoundingMode for RoundDown {}
@@ -1 +1,53 @@
+use super::{Float, Floating};
+use crate::common::*;
+use typenum::Mod;
 
+// normalized floats
+
+/// Marks a float whose significand is not divisible by its base.
+///
+/// The zero is normalized only if it is written as `Float<Base, Z0, Z0>`.
+pub trait Normalized {}
+
+impl<Base> Normalized for Float<Base, Z0, Z0> where Base: Unsigned + NonZero {}
+
+impl<Base, Sig, Exp> Normalized for Float<Base, PInt<Sig>, Exp>
+where
+    Base: Unsigned + NonZero,
+    Sig: Unsigned + NonZero,
+    Exp: Integer,
+    PInt<Sig>: Rem<PInt<Base>>,
+    Mod<PInt<Sig>, PInt<Base>>: NonZero,
+{
+}
+
+impl<Base, Sig, Exp> Normalized for Float<Base, NInt<Sig>, Exp>
+where
+    Base: Unsigned + NonZero,
+    Sig: Unsigned + NonZero,
+    Exp: Integer,
+    NInt<Sig>: Rem<PInt<Base>>,
+    Mod<NInt<Sig>, PInt<Base>>: NonZero,
+{
+}
+
+// representable floats
+
+/// Resolves to the float itself, and fails to compile on
+/// [NotRepresentable](super::NotRepresentable).
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not exactly representable in the target base",
+    label = "not representable"
+)]
+pub trait Representable {
+    type Output: Floating;
+}
+
+impl<Base, Sig, Exp> Representable for Float<Base, Sig, Exp>
+where
+    Base: Unsigned + NonZero,
+    Sig: Integer,
+    Exp: Integer,
+{
+    type Output = Self;
+}
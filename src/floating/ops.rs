@@ -1,13 +1,26 @@
-use super::{Float, Floating};
-use crate::common::*;
+use super::{
+    Float, Floating, NotRepresentable, RoundDown, RoundNearestEven, RoundTowardZero, RoundUp,
+    RoundingMode,
+};
+use crate::{
+    bitset::{BitSet, FromUnsigned},
+    common::*,
+    fraction::{FracDiv, FracMul, Fraction, NFrac, PFrac, Reduce as FracReduce, ToFraction, UFrac},
+    numeric::{Gcd, ToInteger, UnsignedIntegerDiv},
+};
+use typenum::P1;
 
 typ! {
     pub fn Reduce<input>(input: Floating) -> Floating {
         match input {
             #[generics(base: Unsigned + NonZero, sig: Integer, exp: Integer)]
             Float::<base, sig, exp> => {
-                if sig != 0 && sig % base == 0 {
-                    let new_sig: Integer = sig / base;
+                let ibase = PInt::<base>;
+
+                if sig == 0 {
+                    Float::<base, Z0, Z0>
+                } else if sig % ibase == 0 {
+                    let new_sig: Integer = sig / ibase;
                     let new_exp: Integer = exp + 1;
                     Reduce(Float::<base, new_sig, new_exp>)
                 } else {
@@ -17,17 +30,28 @@ typ! {
         }
     }
 
+    pub fn FloatNeg<input>(input: Floating) -> Floating {
+        match input {
+            #[generics(base: Unsigned + NonZero, sig: Integer, exp: Integer)]
+            Float::<base, sig, exp> => {
+                let new_sig: Integer = -sig;
+                Float::<base, new_sig, exp>
+            }
+        }
+    }
+
     pub fn FloatAdd<lhs, rhs>(lhs: Floating, rhs: Floating) -> Floating {
         match (lhs, rhs) {
             #[generics(base: Unsigned + NonZero, lsig: Integer, lexp: Integer, rsig: Integer, rexp: Integer)]
             (Float::<base, lsig, lexp>, Float::<base, rsig, rexp>) => {
+                let ibase = PInt::<base>;
                 let min_exp: Integer = lexp.Min(rexp);
 
-                let lpower = lexp - min_exp;
-                let rpower = rexp - min_exp;
+                let lpower: Integer = lexp - min_exp;
+                let rpower: Integer = rexp - min_exp;
 
-                let lsig = lsig * base.Pow(lpower);
-                let rsig = rsig * base.Pow(rpower);
+                let lsig: Integer = lsig * ibase.Pow(lpower);
+                let rsig: Integer = rsig * ibase.Pow(rpower);
 
                 let out_sig: Integer = lsig + rsig;
                 Reduce(Float::<base, out_sig, min_exp>)
@@ -35,6 +59,11 @@ typ! {
         }
     }
 
+    pub fn FloatSub<lhs, rhs>(lhs: Floating, rhs: Floating) -> Floating {
+        let neg_rhs: Floating = FloatNeg(rhs);
+        FloatAdd(lhs, neg_rhs)
+    }
+
     pub fn FloatMul<lhs, rhs>(lhs: Floating, rhs: Floating) -> Floating {
         match (lhs, rhs) {
             #[generics(base: Unsigned + NonZero, lsig: Integer, lexp: Integer, rsig: Integer, rexp: Integer)]
@@ -45,4 +74,458 @@ typ! {
             }
         }
     }
+
+    pub fn FloatDiv<lhs, rhs>(lhs: Floating, rhs: Floating) {
+        match (lhs, rhs) {
+            #[generics(base: Unsigned + NonZero, lsig: Integer, lexp: Integer, rsig: Integer, rexp: Integer)]
+            (Float::<base, lsig, lexp>, Float::<base, rsig, rexp>) => {
+                let lfrac: Fraction = FloatToFrac(lhs);
+                let rfrac: Fraction = FloatToFrac(rhs);
+                let quot: Fraction = FracDiv(lfrac, rfrac);
+                FracToFloat(quot, base)
+            }
+        }
+    }
+
+    pub fn FloatPow<input, exp>(input: Floating, exp: Integer) {
+        match input {
+            #[generics(base: Unsigned + NonZero, sig: Integer, iexp: Integer)]
+            Float::<base, sig, iexp> => {
+                if exp >= 0 {
+                    let new_sig: Integer = sig.Pow(exp);
+                    let new_exp: Integer = iexp * exp;
+                    Reduce(Float::<base, new_sig, new_exp>)
+                } else {
+                    let neg_exp: Integer = -exp;
+                    let pow: Floating = FloatPow(input, neg_exp);
+                    FloatDiv(Float::<base, P1, Z0>, pow)
+                }
+            }
+        }
+    }
+
+    pub fn FloatCmp<lhs, rhs>(lhs: Floating, rhs: Floating) {
+        let diff: Floating = FloatSub(lhs, rhs);
+
+        match diff {
+            #[generics(base: Unsigned + NonZero, sig: Integer, exp: Integer)]
+            Float::<base, sig, exp> => {
+                if sig > 0 {
+                    Greater
+                } else if sig < 0 {
+                    Less
+                } else {
+                    Equal
+                }
+            }
+        }
+    }
+
+    pub fn FloatToFrac<base, sig, exp>(Float::<base, sig, exp>: Floating) -> Fraction
+    where
+        base: Unsigned + NonZero,
+        sig: Integer,
+        exp: Integer,
+    {
+        let ibase = PInt::<base>;
+        let sig_frac: Fraction = ToFraction(sig);
+
+        if exp >= 0 {
+            let scale: Fraction = ToFraction(ibase.Pow(exp));
+            FracMul(sig_frac, scale)
+        } else {
+            let neg_exp: Integer = -exp;
+            let scale: Fraction = ToFraction(ibase.Pow(neg_exp));
+            FracDiv(sig_frac, scale)
+        }
+    }
+
+    pub fn FracToFloat<frac, base>(frac: Fraction, base: Unsigned + NonZero) {
+        let frac: Fraction = FracReduce(frac);
+
+        match frac {
+            #[generics(num: Unsigned, deno: Unsigned + NonZero)]
+            PFrac::<UFrac<num, deno>> => FracToFloatRecursive(num, deno, base, 0u, false),
+            #[generics(num: Unsigned, deno: Unsigned + NonZero)]
+            NFrac::<UFrac<num, deno>> => FracToFloatRecursive(num, deno, base, 0u, true),
+        }
+    }
+
+    fn FracToFloatRecursive<num, deno, base, count, negative>(
+        num: Unsigned,
+        deno: Unsigned + NonZero,
+        base: Unsigned + NonZero,
+        count: Unsigned,
+        negative: Bit,
+    ) {
+        if deno == 1u {
+            let sig: Integer = ToInteger(num, negative);
+            let exp: Integer = ToInteger(count, true);
+            Reduce(Float::<base, sig, exp>)
+        } else {
+            let gcd: Unsigned = Gcd(deno, base);
+
+            if gcd == 1u {
+                NotRepresentable
+            } else {
+                let new_num: Unsigned = num * (base / gcd);
+                let new_deno: Unsigned + NonZero = deno / gcd;
+                let new_count: Unsigned = count + 1u;
+                FracToFloatRecursive(new_num, new_deno, base, new_count, negative)
+            }
+        }
+    }
+
+    pub fn FloatToBase<input, base>(input: Floating, base: Unsigned + NonZero) {
+        let frac: Fraction = FloatToFrac(input);
+        FracToFloat(frac, base)
+    }
+
+    // rounding
+
+    pub fn RoundToPrecision<input, bits, mode>(
+        input: Floating,
+        bits: Unsigned + NonZero,
+        mode: RoundingMode,
+    ) -> Floating {
+        match input {
+            #[generics(base: Unsigned + NonZero, exp: Integer)]
+            Float::<base, Z0, exp> => Float::<base, Z0, Z0>,
+            #[generics(base: Unsigned + NonZero, mag: Unsigned + NonZero, exp: Integer)]
+            Float::<base, PInt<mag>, exp> => RoundMagnitude(base, mag, exp, bits, mode, false),
+            #[generics(base: Unsigned + NonZero, mag: Unsigned + NonZero, exp: Integer)]
+            Float::<base, NInt<mag>, exp> => RoundMagnitude(base, mag, exp, bits, mode, true),
+        }
+    }
+
+    fn RoundMagnitude<base, mag, exp, bits, mode, negative>(
+        base: Unsigned + NonZero,
+        mag: Unsigned,
+        exp: Integer,
+        bits: Unsigned,
+        mode: RoundingMode,
+        negative: Bit,
+    ) -> Floating {
+        let limit: Unsigned = base.Pow(bits);
+        let excess: Unsigned = DigitExcess(mag, limit, base);
+        let divisor: Unsigned + NonZero = base.Pow(excess);
+        let quot: Unsigned = UnsignedIntegerDiv(mag, divisor);
+        let rem: Unsigned = mag % divisor;
+        let iexcess: Integer = ToInteger(excess, false);
+        let new_exp: Integer = exp + iexcess;
+        let increment: Bit = ShouldIncrement(quot, rem, divisor, mode, negative);
+
+        if increment {
+            let new_quot: Unsigned = quot + 1u;
+            let sig: Integer = ToInteger(new_quot, negative);
+            Reduce(Float::<base, sig, new_exp>)
+        } else {
+            let sig: Integer = ToInteger(quot, negative);
+            Reduce(Float::<base, sig, new_exp>)
+        }
+    }
+
+    fn DigitExcess<mag, limit, base>(mag: Unsigned, limit: Unsigned, base: Unsigned + NonZero) -> Unsigned {
+        if mag < limit {
+            0u
+        } else {
+            let quot: Unsigned = UnsignedIntegerDiv(mag, base);
+            DigitExcess(quot, limit, base) + 1u
+        }
+    }
+
+    fn ShouldIncrement<quot, rem, divisor, mode, negative>(
+        quot: Unsigned,
+        rem: Unsigned,
+        divisor: Unsigned,
+        mode: RoundingMode,
+        negative: Bit,
+    ) -> Bit {
+        if rem == 0u {
+            false
+        } else {
+            match mode {
+                RoundNearestEven => {
+                    let twice: Unsigned = rem * 2u;
+
+                    if twice > divisor {
+                        true
+                    } else if twice < divisor {
+                        false
+                    } else if quot % 2u == 1u {
+                        true
+                    } else {
+                        false
+                    }
+                }
+                RoundTowardZero => false,
+                RoundUp => {
+                    if negative {
+                        false
+                    } else {
+                        true
+                    }
+                }
+                RoundDown => negative,
+            }
+        }
+    }
+
+    // IEEE-754 encoding
+
+    pub fn ToF32Bits<input>(input: Floating) -> Unsigned {
+        ToIeeeBits(input, 24u, 8u)
+    }
+
+    pub fn ToF64Bits<input>(input: Floating) -> Unsigned {
+        ToIeeeBits(input, 53u, 11u)
+    }
+
+    pub fn ToF32BitSet<input>(input: Floating) -> BitSet {
+        let bits: Unsigned = ToF32Bits(input);
+        FromUnsigned(bits, 32u)
+    }
+
+    pub fn ToF64BitSet<input>(input: Floating) -> BitSet {
+        let bits: Unsigned = ToF64Bits(input);
+        FromUnsigned(bits, 64u)
+    }
+
+    fn ToIeeeBits<input, precision, exp_bits>(
+        input: Floating,
+        precision: Unsigned + NonZero,
+        exp_bits: Unsigned + NonZero,
+    ) -> Unsigned {
+        let frac: Fraction = FloatToFrac(input);
+        let frac: Fraction = FracReduce(frac);
+
+        match frac {
+            #[generics(num: Unsigned, deno: Unsigned + NonZero)]
+            PFrac::<UFrac<num, deno>> => IeeeMagnitude(num, deno, precision, exp_bits),
+            #[generics(num: Unsigned, deno: Unsigned + NonZero)]
+            NFrac::<UFrac<num, deno>> => {
+                let two: Unsigned = 2u;
+                let sign_pos: Unsigned = precision + exp_bits - 1u;
+                let sign_bit: Unsigned = two.Pow(sign_pos);
+                IeeeMagnitude(num, deno, precision, exp_bits) + sign_bit
+            }
+        }
+    }
+
+    fn IeeeMagnitude<num, deno, precision, exp_bits>(
+        num: Unsigned,
+        deno: Unsigned + NonZero,
+        precision: Unsigned + NonZero,
+        exp_bits: Unsigned + NonZero,
+    ) -> Unsigned {
+        if num == 0u {
+            0u
+        } else {
+            // the offset of the binary exponent from the one of the smallest subnormal
+            let two: Unsigned = 2u;
+            let bias_pos: Unsigned = exp_bits - 1u;
+            let bias: Unsigned = two.Pow(bias_pos) - 1u;
+            let offset: Unsigned = bias + precision - 2u;
+            IeeeNormalize(num, deno, precision, exp_bits, offset)
+        }
+    }
+
+    fn IeeeNormalize<num, deno, precision, exp_bits, offset>(
+        num: Unsigned,
+        deno: Unsigned + NonZero,
+        precision: Unsigned + NonZero,
+        exp_bits: Unsigned + NonZero,
+        offset: Unsigned,
+    ) -> Unsigned {
+        let two: Unsigned = 2u;
+        let unit_pos: Unsigned = precision - 1u;
+        let low: Unsigned = two.Pow(unit_pos) * deno;
+        let high: Unsigned = low * 2u;
+
+        if num >= high {
+            let new_deno: Unsigned + NonZero = deno * 2u;
+            let new_offset: Unsigned = offset + 1u;
+            IeeeNormalize(num, new_deno, precision, exp_bits, new_offset)
+        } else if num < low {
+            if offset == 0u {
+                IeeeRound(num, deno, precision, exp_bits, offset)
+            } else {
+                let new_num: Unsigned = num * 2u;
+                let new_offset: Unsigned = offset - 1u;
+                IeeeNormalize(new_num, deno, precision, exp_bits, new_offset)
+            }
+        } else {
+            IeeeRound(num, deno, precision, exp_bits, offset)
+        }
+    }
+
+    fn IeeeRound<num, deno, precision, exp_bits, offset>(
+        num: Unsigned,
+        deno: Unsigned + NonZero,
+        precision: Unsigned + NonZero,
+        exp_bits: Unsigned + NonZero,
+        offset: Unsigned,
+    ) -> Unsigned {
+        let quot: Unsigned = UnsignedIntegerDiv(num, deno);
+        let rem: Unsigned = num % deno;
+        let increment: Bit = ShouldIncrement(quot, rem, deno, RoundNearestEven, false);
+
+        if increment {
+            let new_quot: Unsigned = quot + 1u;
+            IeeeEncode(new_quot, precision, exp_bits, offset)
+        } else {
+            IeeeEncode(quot, precision, exp_bits, offset)
+        }
+    }
+
+    fn IeeeEncode<sig, precision, exp_bits, offset>(
+        sig: Unsigned,
+        precision: Unsigned + NonZero,
+        exp_bits: Unsigned + NonZero,
+        offset: Unsigned,
+    ) -> Unsigned {
+        // a significand carried to the next power of two moves to the exponent field by itself
+        let two: Unsigned = 2u;
+        let unit_pos: Unsigned = precision - 1u;
+        let unit: Unsigned = two.Pow(unit_pos);
+        let bits: Unsigned = offset * unit + sig;
+        let max_exp: Unsigned = two.Pow(exp_bits) - 1u;
+        let infinity: Unsigned = max_exp * unit;
+
+        if bits >= infinity {
+            infinity
+        } else {
+            bits
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        control::SameOp,
+        floating::{Float, Float2},
+        numeric::ToFloat,
+        ConstInteger, Frac,
+    };
+    use typenum::consts::*;
+
+    #[test]
+    fn float_test() {
+        let _: SameOp<ReduceOp<Float2<P4, Z0>>, Float2<P1, P2>> = ();
+        let _: SameOp<ReduceOp<Float2<N6, N3>>, Float2<N3, N2>> = ();
+        let _: SameOp<ReduceOp<Float2<Z0, P3>>, Float2<Z0, Z0>> = ();
+        let _: SameOp<ReduceOp<Float<U10, P1200, Z0>>, Float<U10, P12, P2>> = ();
+        let _: SameOp<FloatNegOp<Float2<P3, N1>>, Float2<N3, N1>> = ();
+        let _: SameOp<FloatAddOp<Float2<P3, N1>, Float2<P1, N2>>, Float2<P7, N2>> = ();
+        let _: SameOp<FloatAddOp<Float2<P3, N1>, Float2<N3, N1>>, Float2<Z0, Z0>> = ();
+        let _: SameOp<FloatSubOp<Float2<P1, Z0>, Float2<P3, N1>>, Float2<N1, N1>> = ();
+        let _: SameOp<FloatMulOp<Float2<P3, N1>, Float2<P3, N1>>, Float2<P9, N2>> = ();
+        let _: SameOp<FloatDivOp<Float2<P3, Z0>, Float2<P1, P2>>, Float2<P3, N2>> = ();
+        let _: SameOp<FloatDivOp<Float2<P1, Z0>, Float2<P3, Z0>>, NotRepresentable> = ();
+        let _: SameOp<FloatDivOp<Float<U10, P1, Z0>, Float<U10, P8, Z0>>, Float<U10, P125, N3>> =
+            ();
+        let _: SameOp<FloatPowOp<Float2<P3, N1>, P2>, Float2<P9, N2>> = ();
+        let _: SameOp<FloatPowOp<Float2<P3, N1>, Z0>, Float2<P1, Z0>> = ();
+        let _: SameOp<FloatPowOp<Float2<P1, P1>, N2>, Float2<P1, N2>> = ();
+        let _: SameOp<FloatCmpOp<Float2<P3, N1>, Float2<P1, Z0>>, Greater> = ();
+        let _: SameOp<FloatCmpOp<Float2<P1, Z0>, Float2<P2, N1>>, Equal> = ();
+        let _: SameOp<FloatCmpOp<Float2<N1, Z0>, Float2<P1, N4>>, Less> = ();
+    }
+
+    #[test]
+    fn float_conversion_test() {
+        let _: SameOp<FloatToFracOp<Float2<P3, N1>>, Frac!(3 / 2)> = ();
+        let _: SameOp<FloatToFracOp<Float2<N3, P2>>, Frac!(-12)> = ();
+        let _: SameOp<FloatToFracOp<Float<U10, N125, N3>>, Frac!(-1 / 8)> = ();
+        let _: SameOp<FracToFloatOp<Frac!(3 / 4), U2>, Float2<P3, N2>> = ();
+        let _: SameOp<FracToFloatOp<Frac!(-6), U2>, Float2<N3, P1>> = ();
+        let _: SameOp<FracToFloatOp<Frac!(0), U2>, Float2<Z0, Z0>> = ();
+        let _: SameOp<FracToFloatOp<Frac!(1 / 3), U2>, NotRepresentable> = ();
+        let _: SameOp<FloatToBaseOp<Float2<P1, N3>, U10>, Float<U10, P125, N3>> = ();
+        let _: SameOp<FloatToBaseOp<Float<U10, P5, N1>, U2>, Float2<P1, N1>> = ();
+        let _: SameOp<FloatToBaseOp<Float<U10, P1, N1>, U2>, NotRepresentable> = ();
+    }
+
+    #[test]
+    fn to_float_test() {
+        assert_eq!(<Float<U10, P125, N3>>::to_f64(), 0.125);
+        assert_eq!(<Float2<N3, P2>>::to_f64(), -12.0);
+        assert_eq!(<Float2<P1, P1023>>::to_f64(), 2f64.powi(1023));
+        assert_eq!(<Float2<P1, P1024>>::to_f64(), f64::INFINITY);
+        assert_eq!(
+            <Float2<P1, ConstInteger!(-1022)>>::to_f64(),
+            f64::MIN_POSITIVE
+        );
+        assert_eq!(<Float2<P1, ConstInteger!(-1074)>>::to_f64(), 5e-324);
+        assert_eq!(<Float2<P3, ConstInteger!(-1075)>>::to_f64(), 1e-323);
+        assert_eq!(<Float2<P1, ConstInteger!(-1075)>>::to_f64(), 0.0);
+        assert_eq!(<Float<U10, P1, ConstInteger!(-320)>>::to_f64(), 1e-320);
+        assert_eq!(
+            <Float<U10, ConstInteger!(17976931348623157), ConstInteger!(292)>>::to_f64(),
+            f64::MAX
+        );
+        assert_eq!(
+            <Float<U10, P1, ConstInteger!(309)>>::to_f64(),
+            f64::INFINITY
+        );
+        assert_eq!(<Float<U10, N7, N1>>::to_f64(), -0.7);
+        assert_eq!(<Float<U10, N7, N1>>::to_f32(), -0.7f32);
+        // rounds to the f32 midpoint 1 + 2^-24 in f64, but is slightly above it
+        assert_eq!(
+            <Float<U10, ConstInteger!(10000000596046448), ConstInteger!(-16)>>::to_f32(),
+            f32::from_bits(1f32.to_bits() + 1)
+        );
+        assert_eq!(
+            <Float2<P1, ConstInteger!(-149)>>::to_f32(),
+            f32::from_bits(1)
+        );
+    }
+
+    #[test]
+    fn round_to_precision_test() {
+        let _: SameOp<RoundToPrecisionOp<Float2<P7, Z0>, U2, RoundNearestEven>, Float2<P1, P3>> =
+            ();
+        let _: SameOp<RoundToPrecisionOp<Float2<P7, Z0>, U2, RoundTowardZero>, Float2<P3, P1>> = ();
+        let _: SameOp<RoundToPrecisionOp<Float2<P7, Z0>, U2, RoundUp>, Float2<P1, P3>> = ();
+        let _: SameOp<RoundToPrecisionOp<Float2<P7, Z0>, U2, RoundDown>, Float2<P3, P1>> = ();
+        let _: SameOp<RoundToPrecisionOp<Float2<N7, Z0>, U2, RoundNearestEven>, Float2<N1, P3>> =
+            ();
+        let _: SameOp<RoundToPrecisionOp<Float2<N7, Z0>, U2, RoundTowardZero>, Float2<N3, P1>> = ();
+        let _: SameOp<RoundToPrecisionOp<Float2<N7, Z0>, U2, RoundUp>, Float2<N3, P1>> = ();
+        let _: SameOp<RoundToPrecisionOp<Float2<N7, Z0>, U2, RoundDown>, Float2<N1, P3>> = ();
+        let _: SameOp<RoundToPrecisionOp<Float2<P5, Z0>, U2, RoundNearestEven>, Float2<P1, P2>> =
+            ();
+        let _: SameOp<RoundToPrecisionOp<Float2<P3, N1>, U4, RoundNearestEven>, Float2<P3, N1>> =
+            ();
+        let _: SameOp<RoundToPrecisionOp<Float2<Z0, P2>, U4, RoundUp>, Float2<Z0, Z0>> = ();
+        let _: SameOp<
+            RoundToPrecisionOp<Float<U10, P125, N5>, U2, RoundNearestEven>,
+            Float<U10, P12, N4>,
+        > = ();
+    }
+
+    #[test]
+    fn ieee_encoding_test() {
+        use crate::{bitset::ToUnsignedOp, ConstInteger, ConstUnsigned, Float};
+
+        let _: SameOp<ToF32BitsOp<Float!(1.0)>, ConstUnsigned!(0x3f80_0000)> = ();
+        let _: SameOp<ToF32BitsOp<Float!(-2.5)>, ConstUnsigned!(0xc020_0000)> = ();
+        let _: SameOp<ToF32BitsOp<Float!(0.1)>, ConstUnsigned!(0x3dcc_cccd)> = ();
+        let _: SameOp<ToF32BitsOp<Float!(0)>, U0> = ();
+        let _: SameOp<ToF32BitsOp<Float!(16777217)>, ConstUnsigned!(0x4b80_0000)> = ();
+        let _: SameOp<ToF32BitsOp<Float!(16777219)>, ConstUnsigned!(0x4b80_0002)> = ();
+        let _: SameOp<ToF32BitsOp<Float2<P1, N149>>, U1> = ();
+        let _: SameOp<ToF32BitsOp<Float2<P3, N150>>, U2> = ();
+        let _: SameOp<ToF32BitsOp<Float2<P5, N150>>, U2> = ();
+        let _: SameOp<ToF32BitsOp<Float2<P1, P128>>, ConstUnsigned!(0x7f80_0000)> = ();
+        let _: SameOp<ToF64BitsOp<Float!(1.0)>, ConstUnsigned!(0x3ff0_0000_0000_0000)> = ();
+        let _: SameOp<ToF64BitsOp<Float!(0.1)>, ConstUnsigned!(0x3fb9_9999_9999_999a)> = ();
+        let _: SameOp<ToF64BitsOp<Float2<N3, N2>>, ConstUnsigned!(0xbfe8_0000_0000_0000)> = ();
+        let _: SameOp<ToF64BitsOp<Float2<P1, ConstInteger!(-1074)>>, U1> = ();
+
+        let _: SameOp<ToUnsignedOp<ToF32BitSetOp<Float!(0.1)>>, ToF32BitsOp<Float!(0.1)>> = ();
+        let _: SameOp<crate::bitset::LengthOp<ToF64BitSetOp<Float!(1.0)>>, U64> = ();
+    }
 }
@@ -1,23 +1,151 @@
+/// Builds a reduced type that implements [UFraction](crate::fraction::UFraction).
+///
+/// It accepts a fraction of integer literals `UFrac!(3 / 4)`, a decimal literal
+/// `UFrac!(0.125)` or `UFrac!(1.25e-3)`, an integer literal `UFrac!(3)`, or typenum types
+/// and fractions on either side of the division `UFrac!(U3 / U4)`.
 #[macro_export]
 macro_rules! UFrac {
     ($num:literal / $denom:literal) => {
-        $crate::fraction::unsigned::UFrac<typ::tyuint!($num), typ::tyuint!($denom)>
+        $crate::fraction::ReduceOp<
+            $crate::fraction::UFrac<typ::tyuint!($num), typ::tyuint!($denom)>
+        >
+    };
+    ($value:literal) => {
+        $crate::fraction::ReduceOp<
+            $crate::fraction::UFrac<
+                $crate::ConstUnsigned!($crate::literal::numerator(stringify!($value))),
+                $crate::ConstUnsigned!($crate::literal::denominator(stringify!($value))),
+            >
+        >
+    };
+    (@split [$($num:tt)+] / $($denom:tt)+) => {
+        $crate::fraction::UFracDivOp<
+            $crate::fraction::ToUFractionOp<$($num)+>,
+            $crate::fraction::ToUFractionOp<$($denom)+>,
+        >
+    };
+    (@split [$($num:tt)*] $token:tt $($tokens:tt)*) => {
+        $crate::UFrac!(@split [$($num)* $token] $($tokens)*)
+    };
+    (@split [$($value:tt)+]) => {
+        $crate::fraction::ReduceOp<$crate::fraction::ToUFractionOp<$($value)+>>
+    };
+    ($($tokens:tt)+) => {
+        $crate::UFrac!(@split [] $($tokens)+)
     };
 }
 
+/// Builds a reduced type that implements [Fraction](crate::fraction::Fraction).
+///
+/// It accepts a fraction of integer literals `Frac!(3 / 4)`, where either side can be
+/// negated by `-` or `~`, a decimal literal `Frac!(-0.125)` or `Frac!(1.25e-3)`, an integer
+/// literal `Frac!(3)`, or typenum types and fractions on either side of the division
+/// `Frac!(N3 / U4)`.
 #[macro_export]
 macro_rules! Frac {
+    (- $num:literal / - $denom:literal) => {
+        $crate::fraction::ReduceOp<
+            $crate::fraction::PFrac<
+                $crate::fraction::UFrac<typ::tyuint!($num), typ::tyuint!($denom)>
+            >
+        >
+    };
+    (- $num:literal / $denom:literal) => {
+        $crate::fraction::ReduceOp<
+            $crate::fraction::NFrac<
+                $crate::fraction::UFrac<typ::tyuint!($num), typ::tyuint!($denom)>
+            >
+        >
+    };
+    ($num:literal / - $denom:literal) => {
+        $crate::fraction::ReduceOp<
+            $crate::fraction::NFrac<
+                $crate::fraction::UFrac<typ::tyuint!($num), typ::tyuint!($denom)>
+            >
+        >
+    };
     ($num:literal / $denom:literal) => {
-        $crate::fraction::signed::PFrac<$crate::fraction::unsigned::UFrac<typ::tyuint!($num), typ::tyuint!($denom)>>
+        $crate::fraction::ReduceOp<
+            $crate::fraction::PFrac<
+                $crate::fraction::UFrac<typ::tyuint!($num), typ::tyuint!($denom)>
+            >
+        >
     };
     (~ $num:literal / $denom:literal) => {
-        $crate::fraction::signed::NFrac<$crate::fraction::unsigned::UFrac<typ::tyuint!($num), typ::tyuint!($denom)>>
+        $crate::Frac!(- $num / $denom)
     };
     ($num:literal / ~ $denom:literal) => {
-        $crate::fraction::signed::NFrac<$crate::fraction::unsigned::UFrac<typ::tyuint!($num), typ::tyuint!($denom)>>
+        $crate::Frac!($num / - $denom)
     };
     (~ $num:literal / ~ $denom:literal) => {
-        $crate::fraction::signed::PFrac<$crate::fraction::unsigned::UFrac<typ::tyuint!($num), typ::tyuint!($denom)>>
+        $crate::Frac!(- $num / - $denom)
+    };
+    (- $value:literal) => {
+        $crate::fraction::ReduceOp<
+            $crate::fraction::NFrac<$crate::UFrac!($value)>
+        >
+    };
+    ($value:literal) => {
+        $crate::fraction::PFrac<$crate::UFrac!($value)>
+    };
+    (@split [$($num:tt)+] / $($denom:tt)+) => {
+        $crate::fraction::FracDivOp<
+            $crate::fraction::ToFractionOp<$($num)+>,
+            $crate::fraction::ToFractionOp<$($denom)+>,
+        >
+    };
+    (@split [$($num:tt)*] $token:tt $($tokens:tt)*) => {
+        $crate::Frac!(@split [$($num)* $token] $($tokens)*)
+    };
+    (@split [$($value:tt)+]) => {
+        $crate::fraction::ReduceOp<$crate::fraction::ToFractionOp<$($value)+>>
+    };
+    ($($tokens:tt)+) => {
+        $crate::Frac!(@split [] $($tokens)+)
+    };
+}
+
+/// Builds a reduced base-10 [Float](crate::floating::Float) from a decimal literal.
+///
+/// It accepts a decimal literal `Float!(0.125)`, a literal in scientific notation
+/// `Float!(1.25e-3)` or an integer literal `Float!(3)`, optionally negated by `-`.
+/// Literals with a radix prefix or a type suffix fail to compile.
+///
+/// ```compile_fail
+/// fn parse(_: type_freak::Float!(0b11)) {}
+/// ```
+#[macro_export]
+macro_rules! Float {
+    (- $value:literal) => {
+        $crate::floating::FloatNegOp<$crate::Float!($value)>
+    };
+    ($value:literal) => {
+        $crate::floating::ReduceOp<
+            $crate::floating::Float<
+                typ::tyuint!(10),
+                $crate::numeric::ToIntegerOp<
+                    $crate::ConstUnsigned!($crate::literal::significand(stringify!($value))),
+                    $crate::common::B0,
+                >,
+                $crate::ConstInteger!($crate::literal::float_exponent(stringify!($value))),
+            >
+        >
+    };
+}
+
+/// Builds a reduced base-2 [Float](crate::floating::Float) from a decimal literal.
+///
+/// It accepts the same literals as [Float!](crate::Float). Literals that cannot be exactly
+/// represented in base 2, such as `Float2!(0.1)`, fail to compile.
+#[macro_export]
+macro_rules! Float2 {
+    (- $value:literal) => {
+        <$crate::floating::FracToFloatOp<$crate::Frac!(- $value), typ::tyuint!(2)>
+            as $crate::floating::Representable>::Output
+    };
+    ($value:literal) => {
+        <$crate::floating::FracToFloatOp<$crate::Frac!($value), typ::tyuint!(2)>
+            as $crate::floating::Representable>::Output
     };
 }
 
@@ -25,9 +153,10 @@ macro_rules! Frac {
 mod tests {
     use crate::{
         control::SameOp,
+        floating::{Float, Float2},
         fraction::{NFrac, PFrac, UFrac},
     };
-    use typenum::consts::*;
+    use typenum::{consts::*, UInt, UTerm};
 
     #[test]
     fn frac_macros() {
@@ -37,4 +166,57 @@ mod tests {
         let _: SameOp<Frac!(3 / ~ 4), NFrac<UFrac<U3, U4>>> = ();
         let _: SameOp<Frac!(~ 3 / ~ 4), PFrac<UFrac<U3, U4>>> = ();
     }
+
+    #[test]
+    fn reduced_frac_macros() {
+        let _: SameOp<UFrac!(6 / 8), UFrac<U3, U4>> = ();
+        let _: SameOp<UFrac!(0 / 8), UFrac<U0, U1>> = ();
+        let _: SameOp<UFrac!(0.125), UFrac<U1, U8>> = ();
+        let _: SameOp<UFrac!(2.50), UFrac<U5, U2>> = ();
+        let _: SameOp<UFrac!(1.25e-3), UFrac<U1, U800>> = ();
+        let _: SameOp<UFrac!(1.5e2), UFrac<U150, U1>> = ();
+        let _: SameOp<UFrac!(3), UFrac<U3, U1>> = ();
+        let _: SameOp<UFrac!(U6 / U4), UFrac<U3, U2>> = ();
+        let _: SameOp<UFrac!(U3), UFrac<U3, U1>> = ();
+        let _: SameOp<UFrac!(UFrac<U2, U4>), UFrac<U1, U2>> = ();
+        let _: SameOp<UFrac!(UFrac<U1, U2> / U3), UFrac<U1, U6>> = ();
+
+        let _: SameOp<Frac!(6 / 8), PFrac<UFrac<U3, U4>>> = ();
+        let _: SameOp<Frac!(-3 / 4), NFrac<UFrac<U3, U4>>> = ();
+        let _: SameOp<Frac!(3 / -4), NFrac<UFrac<U3, U4>>> = ();
+        let _: SameOp<Frac!(-3 / -4), PFrac<UFrac<U3, U4>>> = ();
+        let _: SameOp<Frac!(-6 / 8), NFrac<UFrac<U3, U4>>> = ();
+        let _: SameOp<Frac!(-0 / 8), PFrac<UFrac<U0, U1>>> = ();
+        let _: SameOp<Frac!(~ 0 / 8), PFrac<UFrac<U0, U1>>> = ();
+        let _: SameOp<Frac!(0.125), PFrac<UFrac<U1, U8>>> = ();
+        let _: SameOp<Frac!(-0.125), NFrac<UFrac<U1, U8>>> = ();
+        let _: SameOp<Frac!(-0.0), PFrac<UFrac<U0, U1>>> = ();
+        let _: SameOp<Frac!(1.25e-3), PFrac<UFrac<U1, U800>>> = ();
+        let _: SameOp<Frac!(3), PFrac<UFrac<U3, U1>>> = ();
+        let _: SameOp<Frac!(-3), NFrac<UFrac<U3, U1>>> = ();
+        let _: SameOp<Frac!(P6 / N4), NFrac<UFrac<U3, U2>>> = ();
+        let _: SameOp<Frac!(N6 / U4), NFrac<UFrac<U3, U2>>> = ();
+        let _: SameOp<Frac!(Z0 / N4), PFrac<UFrac<U0, U1>>> = ();
+        let _: SameOp<Frac!(N3), NFrac<UFrac<U3, U1>>> = ();
+        let _: SameOp<Frac!(UInt<UTerm, B1>), PFrac<UFrac<U1, U1>>> = ();
+        let _: SameOp<Frac!(NFrac<UFrac<U0, U3>>), PFrac<UFrac<U0, U1>>> = ();
+        let _: SameOp<Frac!(Frac!(1 / 2) / UFrac<U3, U1>), PFrac<UFrac<U1, U6>>> = ();
+    }
+
+    #[test]
+    fn float_macros() {
+        let _: SameOp<Float!(1.25e-3), Float<U10, P125, N5>> = ();
+        let _: SameOp<Float!(-1.25e-3), Float<U10, N125, N5>> = ();
+        let _: SameOp<Float!(1.50), Float<U10, P15, N1>> = ();
+        let _: SameOp<Float!(1200), Float<U10, P12, P2>> = ();
+        let _: SameOp<Float!(1.2e3), Float<U10, P12, P2>> = ();
+        let _: SameOp<Float!(0.0), Float<U10, Z0, Z0>> = ();
+        let _: SameOp<Float!(-0.0), Float<U10, Z0, Z0>> = ();
+
+        let _: SameOp<Float2!(0.75), Float2<P3, N2>> = ();
+        let _: SameOp<Float2!(-0.75), Float2<N3, N2>> = ();
+        let _: SameOp<Float2!(12), Float2<P3, P2>> = ();
+        let _: SameOp<Float2!(6.25e-2), Float2<P1, N4>> = ();
+        let _: SameOp<Float2!(0), Float2<Z0, Z0>> = ();
+    }
 }
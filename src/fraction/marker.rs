@@ -13,3 +13,11 @@ pub trait Fraction {
 // irreducible fractions
 
 pub trait Irreducible {}
+
+// canonical fractions
+
+/// Marks the unique representation of a fraction value.
+///
+/// A canonical fraction is irreducible, and zero is always `PFrac<UFrac<U0, U1>>`.
+/// Two canonical fractions are the same type if and only if they are numerically equal.
+pub trait Canonical {}
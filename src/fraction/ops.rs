@@ -1,7 +1,41 @@
 use super::{Fraction, NFrac, PFrac, UFrac, UFraction};
-use crate::{common::*, numeric::Gcd};
+use crate::{
+    common::*,
+    list::{Cons, List, Nil},
+    numeric::{Gcd, UnsignedIntegerDiv},
+};
+use typenum::U1;
 
 typ! {
+    pub fn ToUFraction<value>(value: _) -> UFraction {
+        match value {
+            UTerm => UFrac::<UTerm, U1>,
+            #[generics(uint: Unsigned, bit: Bit)]
+            UInt::<uint, bit> => UFrac::<UInt<uint, bit>, U1>,
+            #[generics(num: Unsigned, deno: Unsigned + NonZero)]
+            UFrac::<num, deno> => UFrac::<num, deno>,
+        }
+    }
+
+    pub fn ToFraction<value>(value: _) -> Fraction {
+        match value {
+            UTerm => PFrac::<UFrac<UTerm, U1>>,
+            #[generics(uint: Unsigned, bit: Bit)]
+            UInt::<uint, bit> => PFrac::<UFrac<UInt<uint, bit>, U1>>,
+            Z0 => PFrac::<UFrac<UTerm, U1>>,
+            #[generics(uint: Unsigned + NonZero)]
+            PInt::<uint> => PFrac::<UFrac<uint, U1>>,
+            #[generics(uint: Unsigned + NonZero)]
+            NInt::<uint> => NFrac::<UFrac<uint, U1>>,
+            #[generics(num: Unsigned, deno: Unsigned + NonZero)]
+            UFrac::<num, deno> => PFrac::<UFrac<num, deno>>,
+            #[generics(frac: UFraction)]
+            PFrac::<frac> => PFrac::<frac>,
+            #[generics(frac: UFraction)]
+            NFrac::<frac> => NFrac::<frac>,
+        }
+    }
+
     pub fn Reciprocal<frac>(frac: _) {
         match frac {
             #[generics(num: Unsigned + NonZero, deno: Unsigned + NonZero)]
@@ -27,7 +61,11 @@ typ! {
                 let gcd = Gcd(num, deno);
                 let num: Unsigned = num / gcd;
                 let deno: Unsigned + NonZero = deno / gcd;
-                NFrac::<UFrac<num, deno>>
+                if num == 0u {
+                    PFrac::<UFrac<num, deno>>
+                } else {
+                    NFrac::<UFrac<num, deno>>
+                }
             }
             #[generics(num: Unsigned, deno: Unsigned + NonZero)]
             PFrac::<UFrac<num, deno>> => {
@@ -215,6 +253,9 @@ typ! {
 
     pub fn FracCmp<lhs, rhs>(lhs: Fraction, rhs: Fraction)
     {
+        let lhs: Fraction = Reduce(lhs);
+        let rhs: Fraction = Reduce(rhs);
+
         match (lhs, rhs) {
             #[generics(lfrac: UFraction, rfrac: UFraction)]
             (PFrac::<lfrac>, PFrac::<rfrac>) => {
@@ -241,17 +282,118 @@ typ! {
     }
 }
 
+typ! {
+    pub fn ToContinuedFraction<num, deno>(UFrac::<num, deno>: UFraction) -> List
+    where
+        num: Unsigned,
+        deno: Unsigned + NonZero,
+    {
+        let quot: Unsigned = UnsignedIntegerDiv(num, deno);
+        let rem: Unsigned = num % deno;
+
+        if rem == 0u {
+            Cons::<quot, Nil>
+        } else {
+            let rem: Unsigned + NonZero = rem;
+            let tail = ToContinuedFraction(UFrac::<deno, rem>);
+            Cons::<quot, tail>
+        }
+    }
+
+    pub fn FromContinuedFraction<head: Unsigned, tail: List>(Cons::<head, tail>: List) -> UFraction {
+        match tail {
+            #[generics(head2, tail2: List)]
+            Cons::<head2, tail2> => {
+                let frac: UFraction = FromContinuedFraction(tail);
+                let reciprocal: UFraction = Reciprocal(frac);
+                UFracAdd(UFrac::<head, U1>, reciprocal)
+            }
+            Nil => UFrac::<head, U1>,
+        }
+    }
+
+    pub fn BestApproximation<frac, max_deno>(frac: UFraction, max_deno: Unsigned + NonZero) -> UFraction {
+        let frac: UFraction = Reduce(frac);
+
+        match frac {
+            #[generics(num: Unsigned, deno: Unsigned + NonZero)]
+            UFrac::<num, deno> => {
+                if deno <= max_deno {
+                    frac
+                } else {
+                    BestApproximationRecursive(frac, max_deno, 0u, 1u, 1u, 0u, num, deno)
+                }
+            }
+        }
+    }
+
+    fn BestApproximationRecursive<frac, max_deno, p0, q0, p1, q1, num, deno>(
+        frac: UFraction,
+        max_deno: Unsigned + NonZero,
+        p0: Unsigned,
+        q0: Unsigned,
+        p1: Unsigned,
+        q1: Unsigned,
+        num: Unsigned,
+        deno: Unsigned + NonZero,
+    ) -> UFraction {
+        let quot: Unsigned = UnsignedIntegerDiv(num, deno);
+        let q2: Unsigned = q0 + quot * q1;
+
+        if q2 > max_deno {
+            // choose between the last convergent and the closest semiconvergent
+            let q1: Unsigned + NonZero = q1;
+            let k: Unsigned = UnsignedIntegerDiv(max_deno - q0, q1);
+            let semi_num: Unsigned = p0 + k * p1;
+            let semi_deno: Unsigned + NonZero = q0 + k * q1;
+
+            let semi = UFrac::<semi_num, semi_deno>;
+            let conv = UFrac::<p1, q1>;
+            let semi_diff: UFraction = UFracAbsDiff(semi, frac);
+            let conv_diff: UFraction = UFracAbsDiff(conv, frac);
+
+            match UFracCmp(conv_diff, semi_diff) {
+                Greater => semi,
+                Equal => conv,
+                Less => conv,
+            }
+        } else {
+            let p2: Unsigned = p0 + quot * p1;
+            let rem: Unsigned = num % deno;
+
+            if rem == 0u {
+                let q2: Unsigned + NonZero = q2;
+                UFrac::<p2, q2>
+            } else {
+                let rem: Unsigned + NonZero = rem;
+                BestApproximationRecursive(frac, max_deno, p1, q1, p2, q2, deno, rem)
+            }
+        }
+    }
+
+    fn UFracAbsDiff<lhs, rhs>(lhs: UFraction, rhs: UFraction) -> UFraction {
+        match UFracCmp(lhs, rhs) {
+            Greater => UFracSub(lhs, rhs),
+            Equal => UFracSub(lhs, rhs),
+            Less => UFracSub(rhs, lhs),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{control::SameOp, Frac, UFrac};
+    use crate::{control::SameOp, fraction::Canonical, Frac, List, UFrac};
+    use typenum::consts::*;
 
     #[test]
     fn frac_test() {
-        let _: SameOp<ReduceOp<UFrac!(2 / 4)>, UFrac!(1 / 2)> = ();
-        let _: SameOp<ReduceOp<UFrac!(0 / 4)>, UFrac!(0 / 1)> = ();
-        let _: SameOp<ReduceOp<Frac!(3 / 9)>, Frac!(1 / 3)> = ();
-        let _: SameOp<ReduceOp<Frac!(3 / ~9)>, Frac!(~1 / 3)> = ();
+        let _: SameOp<ReduceOp<UFrac<U2, U4>>, UFrac!(1 / 2)> = ();
+        let _: SameOp<ReduceOp<UFrac<U0, U4>>, UFrac!(0 / 1)> = ();
+        let _: SameOp<ReduceOp<PFrac<UFrac<U3, U9>>>, Frac!(1 / 3)> = ();
+        let _: SameOp<ReduceOp<NFrac<UFrac<U3, U9>>>, Frac!(~1 / 3)> = ();
+        let _: SameOp<ReduceOp<NFrac<UFrac<U0, U3>>>, Frac!(0 / 1)> = ();
+        let _: SameOp<ReduceOp<NFrac<UFrac<U0, U1>>>, Frac!(0 / 1)> = ();
         let _: SameOp<ReciprocalOp<UFrac!(3 / 2)>, UFrac!(2 / 3)> = ();
         let _: SameOp<ReciprocalOp<Frac!(3 / 2)>, Frac!(2 / 3)> = ();
         let _: SameOp<ReciprocalOp<Frac!(~3 / 2)>, Frac!(~2 / 3)> = ();
@@ -260,6 +402,8 @@ mod tests {
         let _: SameOp<FracAddOp<Frac!(1 / 2), Frac!(~1 / 3)>, Frac!(1 / 6)> = ();
         let _: SameOp<FracAddOp<Frac!(~1 / 2), Frac!(1 / 3)>, Frac!(~1 / 6)> = ();
         let _: SameOp<FracAddOp<Frac!(~1 / 2), Frac!(~1 / 3)>, Frac!(~5 / 6)> = ();
+        let _: SameOp<FracAddOp<Frac!(~1 / 2), Frac!(1 / 2)>, Frac!(0 / 1)> = ();
+        let _: SameOp<FracAddOp<Frac!(1 / 2), Frac!(~1 / 2)>, Frac!(0 / 1)> = ();
         let _: SameOp<UFracSubOp<UFrac!(1 / 2), UFrac!(1 / 3)>, UFrac!(1 / 6)> = ();
         let _: SameOp<FracSubOp<Frac!(1 / 2), Frac!(1 / 3)>, Frac!(1 / 6)> = ();
         let _: SameOp<FracSubOp<Frac!(1 / 2), Frac!(~1 / 3)>, Frac!(5 / 6)> = ();
@@ -270,6 +414,7 @@ mod tests {
         let _: SameOp<FracMulOp<Frac!(~2 / 3), Frac!(9 / 4)>, Frac!(~3 / 2)> = ();
         let _: SameOp<FracMulOp<Frac!(2 / 3), Frac!(~9 / 4)>, Frac!(~3 / 2)> = ();
         let _: SameOp<FracMulOp<Frac!(~2 / 3), Frac!(~9 / 4)>, Frac!(3 / 2)> = ();
+        let _: SameOp<FracMulOp<PFrac<UFrac<U0, U3>>, Frac!(~9 / 4)>, Frac!(0 / 1)> = ();
         let _: SameOp<UFracDivOp<UFrac!(2 / 3), UFrac!(4 / 9)>, UFrac!(3 / 2)> = ();
         let _: SameOp<FracDivOp<Frac!(2 / 3), Frac!(4 / 9)>, Frac!(3 / 2)> = ();
         let _: SameOp<FracDivOp<Frac!(~2 / 3), Frac!(4 / 9)>, Frac!(~3 / 2)> = ();
@@ -278,10 +423,73 @@ mod tests {
         let _: SameOp<UFracCmpOp<UFrac!(1 / 3), UFrac!(1 / 2)>, Less> = ();
         let _: SameOp<UFracCmpOp<UFrac!(1 / 2), UFrac!(1 / 3)>, Greater> = ();
         let _: SameOp<UFracCmpOp<UFrac!(3 / 7), UFrac!(3 / 7)>, Equal> = ();
-        let _: SameOp<UFracCmpOp<UFrac!(3 / 7), UFrac!(6 / 14)>, Equal> = ();
+        let _: SameOp<UFracCmpOp<UFrac!(3 / 7), UFrac<U6, U14>>, Equal> = ();
         let _: SameOp<FracCmpOp<Frac!(1 / 3), Frac!(1 / 2)>, Less> = ();
         let _: SameOp<FracCmpOp<Frac!(1 / 3), Frac!(~1 / 2)>, Greater> = ();
         let _: SameOp<FracCmpOp<Frac!(~1 / 3), Frac!(1 / 2)>, Less> = ();
         let _: SameOp<FracCmpOp<Frac!(~1 / 3), Frac!(~1 / 2)>, Greater> = ();
+        let _: SameOp<FracCmpOp<PFrac<UFrac<U0, U3>>, NFrac<UFrac<U0, U2>>>, Equal> = ();
+        let _: SameOp<FracCmpOp<NFrac<UFrac<U0, U3>>, Frac!(~1 / 2)>, Greater> = ();
+    }
+
+    #[test]
+    fn frac_canonical_test() {
+        fn assert_canonical<F: Canonical>() {}
+
+        assert_canonical::<UFrac!(0 / 1)>();
+        assert_canonical::<UFrac!(2 / 3)>();
+        assert_canonical::<Frac!(0 / 1)>();
+        assert_canonical::<Frac!(~2 / 3)>();
+        assert_canonical::<ReduceOp<NFrac<UFrac<U0, U5>>>>();
+        assert_canonical::<FracSubOp<Frac!(~1 / 4), NFrac<UFrac<U2, U8>>>>();
+    }
+
+    #[test]
+    fn frac_conversion_test() {
+        let _: SameOp<ToUFractionOp<U0>, UFrac!(0 / 1)> = ();
+        let _: SameOp<ToUFractionOp<U3>, UFrac!(3 / 1)> = ();
+        let _: SameOp<ToUFractionOp<UFrac!(2 / 3)>, UFrac!(2 / 3)> = ();
+        let _: SameOp<ToFractionOp<U3>, Frac!(3 / 1)> = ();
+        let _: SameOp<ToFractionOp<Z0>, Frac!(0 / 1)> = ();
+        let _: SameOp<ToFractionOp<P2>, Frac!(2 / 1)> = ();
+        let _: SameOp<ToFractionOp<N2>, Frac!(~2 / 1)> = ();
+        let _: SameOp<ToFractionOp<UFrac!(2 / 3)>, Frac!(2 / 3)> = ();
+        let _: SameOp<ToFractionOp<Frac!(~2 / 3)>, Frac!(~2 / 3)> = ();
+    }
+
+    #[test]
+    fn frac_std_ops_test() {
+        let _: UFrac!(5 / 6) = <UFrac!(1 / 2)>::new() + <UFrac!(1 / 3)>::new();
+        let _: UFrac!(3 / 2) = <UFrac!(1 / 2)>::new() * U3::new();
+        let _: UFrac!(7 / 2) = U3::new() + <UFrac!(1 / 2)>::new();
+        let _: UFrac!(1 / 6) = <UFrac!(1 / 2)>::new() / U3::new();
+        let _: Frac!(~5 / 2) = <UFrac!(1 / 2)>::new() - P3::new();
+        let _: Frac!(3 / 2) = <Frac!(1 / 2)>::new() * U3::new();
+        let _: Frac!(~3 / 2) = <Frac!(1 / 2)>::new() * N3::new();
+        let _: Frac!(5 / 2) = P2::new() + <Frac!(1 / 2)>::new();
+        let _: Frac!(~1 / 6) = <UFrac!(1 / 3)>::new() - <Frac!(1 / 2)>::new();
+        let _: Frac!(2 / 1) = <Frac!(~1 / 2)>::new() / <Frac!(~1 / 4)>::new();
+
+        assert!(<UFrac!(1 / 3)>::new() < <UFrac!(1 / 2)>::new());
+        assert!(<UFrac!(3 / 7)>::new() == UFrac::<U6, U14>::new());
+        assert!(<Frac!(~1 / 3)>::new() > <Frac!(~1 / 2)>::new());
+        assert!(<Frac!(1 / 3)>::new() > <Frac!(~1 / 2)>::new());
+        assert!(<Frac!(1 / 3)>::new() != <Frac!(1 / 2)>::new());
+    }
+
+    #[test]
+    fn continued_fraction_test() {
+        let _: SameOp<ToContinuedFractionOp<UFrac!(0 / 1)>, List![U0]> = ();
+        let _: SameOp<ToContinuedFractionOp<UFrac!(3 / 1)>, List![U3]> = ();
+        let _: SameOp<ToContinuedFractionOp<UFrac!(355 / 113)>, List![U3, U7, U16]> = ();
+        let _: SameOp<ToContinuedFractionOp<UFrac!(16 / 9)>, List![U1, U1, U3, U2]> = ();
+        let _: SameOp<FromContinuedFractionOp<List![U3]>, UFrac!(3 / 1)> = ();
+        let _: SameOp<FromContinuedFractionOp<List![U3, U7, U16]>, UFrac!(355 / 113)> = ();
+        let _: SameOp<FromContinuedFractionOp<List![U1, U1, U3, U2]>, UFrac!(16 / 9)> = ();
+        let _: SameOp<BestApproximationOp<UFrac!(3 / 4), U10>, UFrac!(3 / 4)> = ();
+        let _: SameOp<BestApproximationOp<UFrac<U6, U8>, U4>, UFrac!(3 / 4)> = ();
+        let _: SameOp<BestApproximationOp<UFrac!(355 / 113), U10>, UFrac!(22 / 7)> = ();
+        let _: SameOp<BestApproximationOp<UFrac!(16 / 9), U8>, UFrac!(9 / 5)> = ();
+        let _: SameOp<BestApproximationOp<UFrac!(1920 / 1080), U4>, UFrac!(7 / 4)> = ();
     }
 }
@@ -1,8 +1,47 @@
 use super::{
-    FracAdd, FracAddOp, FracDiv, FracDivOp, FracMul, FracMulOp, FracSub, FracSubOp, Fraction,
-    Irreducible, UFraction,
+    Canonical, FracAdd, FracAddOp, FracCmp, FracCmpOp, FracDiv, FracDivOp, FracMul, FracMulOp,
+    FracSub, FracSubOp, Fraction, Irreducible, ToFraction, ToFractionOp, UFrac, UFraction,
 };
-use crate::common::*;
+use crate::{common::*, numeric::ToFloat};
+use std::cmp::Ordering;
+
+// macros
+
+macro_rules! frac_typenum_impl {
+    ($frac:ident, $op:ident, $method:ident, $typ:ident, $typ_op:ident; [$($generics:tt)*] $ty:ty) => {
+        impl<F, $($generics)*> $op<$ty> for $frac<F>
+        where
+            (): ToFraction<$ty> + $typ<Self, ToFractionOp<$ty>>,
+            F: UFraction,
+        {
+            type Output = $typ_op<Self, ToFractionOp<$ty>>;
+
+            fn $method(self, _rhs: $ty) -> Self::Output {
+                Self::Output::new()
+            }
+        }
+
+        impl<F, $($generics)*> $op<$frac<F>> for $ty
+        where
+            (): ToFraction<$ty> + $typ<ToFractionOp<$ty>, $frac<F>>,
+            F: UFraction,
+        {
+            type Output = $typ_op<ToFractionOp<$ty>, $frac<F>>;
+
+            fn $method(self, _rhs: $frac<F>) -> Self::Output {
+                Self::Output::new()
+            }
+        }
+    };
+    ($frac:ident, $op:ident, $method:ident, $typ:ident, $typ_op:ident) => {
+        frac_typenum_impl!($frac, $op, $method, $typ, $typ_op; [] UTerm);
+        frac_typenum_impl!($frac, $op, $method, $typ, $typ_op; [U: Unsigned, B: Bit] UInt<U, B>);
+        frac_typenum_impl!($frac, $op, $method, $typ, $typ_op; [] Z0);
+        frac_typenum_impl!($frac, $op, $method, $typ, $typ_op; [U: Unsigned + NonZero] PInt<U>);
+        frac_typenum_impl!($frac, $op, $method, $typ, $typ_op; [U: Unsigned + NonZero] NInt<U>);
+        frac_typenum_impl!($frac, $op, $method, $typ, $typ_op; [N: Unsigned, D: Unsigned + NonZero] UFrac<N, D>);
+    };
+}
 
 // positive fraction type
 
@@ -46,6 +85,32 @@ impl<F> Irreducible for PFrac<F> where F: UFraction + Irreducible {}
 
 impl<F> Irreducible for NFrac<F> where F: UFraction + Irreducible {}
 
+// canonical marker
+
+impl<F> Canonical for PFrac<F> where F: UFraction + Canonical {}
+
+impl<F> Canonical for NFrac<F> where F: UFraction + Canonical + NonZero {}
+
+// float conversion
+
+impl<Frac> ToFloat for PFrac<Frac>
+where
+    Frac: UFraction + ToFloat,
+{
+    fn to_f64() -> f64 {
+        Frac::to_f64()
+    }
+}
+
+impl<Frac> ToFloat for NFrac<Frac>
+where
+    Frac: UFraction + ToFloat,
+{
+    fn to_f64() -> f64 {
+        -Frac::to_f64()
+    }
+}
+
 // negation
 
 impl<Frac> Neg for PFrac<Frac>
@@ -181,3 +246,64 @@ where
         Self::Output::new()
     }
 }
+
+// ops against typenum integers and unsigned fractions
+
+frac_typenum_impl!(PFrac, Add, add, FracAdd, FracAddOp);
+frac_typenum_impl!(NFrac, Add, add, FracAdd, FracAddOp);
+frac_typenum_impl!(PFrac, Sub, sub, FracSub, FracSubOp);
+frac_typenum_impl!(NFrac, Sub, sub, FracSub, FracSubOp);
+frac_typenum_impl!(PFrac, Mul, mul, FracMul, FracMulOp);
+frac_typenum_impl!(NFrac, Mul, mul, FracMul, FracMulOp);
+frac_typenum_impl!(PFrac, Div, div, FracDiv, FracDivOp);
+frac_typenum_impl!(NFrac, Div, div, FracDiv, FracDivOp);
+
+// comparison
+
+impl<F, Rhs> PartialEq<Rhs> for PFrac<F>
+where
+    (): FracCmp<Self, Rhs>,
+    F: UFraction,
+    Rhs: Fraction,
+    FracCmpOp<Self, Rhs>: typenum::Ord,
+{
+    fn eq(&self, _other: &Rhs) -> bool {
+        <FracCmpOp<Self, Rhs> as typenum::Ord>::to_ordering() == Ordering::Equal
+    }
+}
+
+impl<F, Rhs> PartialEq<Rhs> for NFrac<F>
+where
+    (): FracCmp<Self, Rhs>,
+    F: UFraction,
+    Rhs: Fraction,
+    FracCmpOp<Self, Rhs>: typenum::Ord,
+{
+    fn eq(&self, _other: &Rhs) -> bool {
+        <FracCmpOp<Self, Rhs> as typenum::Ord>::to_ordering() == Ordering::Equal
+    }
+}
+
+impl<F, Rhs> PartialOrd<Rhs> for PFrac<F>
+where
+    (): FracCmp<Self, Rhs>,
+    F: UFraction,
+    Rhs: Fraction,
+    FracCmpOp<Self, Rhs>: typenum::Ord,
+{
+    fn partial_cmp(&self, _other: &Rhs) -> Option<Ordering> {
+        Some(<FracCmpOp<Self, Rhs> as typenum::Ord>::to_ordering())
+    }
+}
+
+impl<F, Rhs> PartialOrd<Rhs> for NFrac<F>
+where
+    (): FracCmp<Self, Rhs>,
+    F: UFraction,
+    Rhs: Fraction,
+    FracCmpOp<Self, Rhs>: typenum::Ord,
+{
+    fn partial_cmp(&self, _other: &Rhs) -> Option<Ordering> {
+        Some(<FracCmpOp<Self, Rhs> as typenum::Ord>::to_ordering())
+    }
+}
@@ -1,14 +1,91 @@
 use super::{
-    Irreducible, Reciprocal, UFracAdd, UFracAddOp, UFracDiv, UFracDivOp, UFracMul, UFracMulOp,
+    Canonical, FracAdd, FracAddOp, FracDiv, FracDivOp, FracMul, FracMulOp, FracSub, FracSubOp,
+    Fraction, Irreducible, Reciprocal, ToFraction, ToFractionOp, ToUFraction, ToUFractionOp,
+    UFracAdd, UFracAddOp, UFracCmp, UFracCmpOp, UFracDiv, UFracDivOp, UFracMul, UFracMulOp,
     UFracSub, UFracSubOp, UFraction,
 };
 use crate::{
     common::*,
     control::Same,
-    numeric::{Gcd, GcdOp},
+    numeric::{Gcd, GcdOp, ToFloat},
 };
+use std::cmp::Ordering;
 use typenum::U1;
 
+// macros
+
+macro_rules! ufrac_unsigned_impl {
+    ($op:ident, $method:ident, $typ:ident, $typ_op:ident; [$($generics:tt)*] $ty:ty) => {
+        impl<N, D, $($generics)*> $op<$ty> for UFrac<N, D>
+        where
+            (): ToUFraction<$ty> + $typ<Self, ToUFractionOp<$ty>>,
+            N: Unsigned,
+            D: Unsigned + NonZero,
+        {
+            type Output = $typ_op<Self, ToUFractionOp<$ty>>;
+
+            fn $method(self, _rhs: $ty) -> Self::Output {
+                Self::Output::new()
+            }
+        }
+
+        impl<N, D, $($generics)*> $op<UFrac<N, D>> for $ty
+        where
+            (): ToUFraction<$ty> + $typ<ToUFractionOp<$ty>, UFrac<N, D>>,
+            N: Unsigned,
+            D: Unsigned + NonZero,
+        {
+            type Output = $typ_op<ToUFractionOp<$ty>, UFrac<N, D>>;
+
+            fn $method(self, _rhs: UFrac<N, D>) -> Self::Output {
+                Self::Output::new()
+            }
+        }
+    };
+}
+
+macro_rules! ufrac_signed_impl {
+    ($op:ident, $method:ident, $typ:ident, $typ_op:ident; [$($generics:tt)*] $ty:ty) => {
+        impl<N, D, $($generics)*> $op<$ty> for UFrac<N, D>
+        where
+            (): ToFraction<Self> + ToFraction<$ty> + $typ<ToFractionOp<Self>, ToFractionOp<$ty>>,
+            N: Unsigned,
+            D: Unsigned + NonZero,
+        {
+            type Output = $typ_op<ToFractionOp<Self>, ToFractionOp<$ty>>;
+
+            fn $method(self, _rhs: $ty) -> Self::Output {
+                Self::Output::new()
+            }
+        }
+
+        impl<N, D, $($generics)*> $op<UFrac<N, D>> for $ty
+        where
+            (): ToFraction<$ty>
+                + ToFraction<UFrac<N, D>>
+                + $typ<ToFractionOp<$ty>, ToFractionOp<UFrac<N, D>>>,
+            N: Unsigned,
+            D: Unsigned + NonZero,
+        {
+            type Output = $typ_op<ToFractionOp<$ty>, ToFractionOp<UFrac<N, D>>>;
+
+            fn $method(self, _rhs: UFrac<N, D>) -> Self::Output {
+                Self::Output::new()
+            }
+        }
+    };
+}
+
+macro_rules! ufrac_typenum_impl {
+    ($op:ident, $method:ident, $utyp:ident, $utyp_op:ident, $typ:ident, $typ_op:ident) => {
+        ufrac_unsigned_impl!($op, $method, $utyp, $utyp_op; [] UTerm);
+        ufrac_unsigned_impl!($op, $method, $utyp, $utyp_op; [U: Unsigned, B: Bit] UInt<U, B>);
+        ufrac_signed_impl!($op, $method, $typ, $typ_op; [] Z0);
+        ufrac_signed_impl!($op, $method, $typ, $typ_op; [U: Unsigned + NonZero] PInt<U>);
+        ufrac_signed_impl!($op, $method, $typ, $typ_op; [U: Unsigned + NonZero] NInt<U>);
+    };
+}
+
 // unsigned fraction type
 
 pub struct UFrac<Numerators, Denominators>(PhantomData<(Numerators, Denominators)>)
@@ -43,6 +120,26 @@ where
 {
 }
 
+impl<N, D> Canonical for UFrac<N, D>
+where
+    Self: Irreducible,
+    N: Unsigned,
+    D: Unsigned + NonZero,
+{
+}
+
+// float conversion
+
+impl<N, D> ToFloat for UFrac<N, D>
+where
+    N: Unsigned,
+    D: Unsigned + NonZero,
+{
+    fn to_f64() -> f64 {
+        N::U128 as f64 / D::U128 as f64
+    }
+}
+
 // sum
 
 impl<N, D, Rhs> Add<Rhs> for UFrac<N, D>
@@ -107,3 +204,38 @@ where
         Self::Output::new()
     }
 }
+
+// ops against typenum integers
+
+ufrac_typenum_impl!(Add, add, UFracAdd, UFracAddOp, FracAdd, FracAddOp);
+ufrac_typenum_impl!(Sub, sub, UFracSub, UFracSubOp, FracSub, FracSubOp);
+ufrac_typenum_impl!(Mul, mul, UFracMul, UFracMulOp, FracMul, FracMulOp);
+ufrac_typenum_impl!(Div, div, UFracDiv, UFracDivOp, FracDiv, FracDivOp);
+
+// comparison
+
+impl<N, D, Rhs> PartialEq<Rhs> for UFrac<N, D>
+where
+    (): UFracCmp<Self, Rhs>,
+    N: Unsigned,
+    D: Unsigned + NonZero,
+    Rhs: UFraction,
+    UFracCmpOp<Self, Rhs>: typenum::Ord,
+{
+    fn eq(&self, _other: &Rhs) -> bool {
+        <UFracCmpOp<Self, Rhs> as typenum::Ord>::to_ordering() == Ordering::Equal
+    }
+}
+
+impl<N, D, Rhs> PartialOrd<Rhs> for UFrac<N, D>
+where
+    (): UFracCmp<Self, Rhs>,
+    N: Unsigned,
+    D: Unsigned + NonZero,
+    Rhs: UFraction,
+    UFracCmpOp<Self, Rhs>: typenum::Ord,
+{
+    fn partial_cmp(&self, _other: &Rhs) -> Option<Ordering> {
+        Some(<UFracCmpOp<Self, Rhs> as typenum::Ord>::to_ordering())
+    }
+}
@@ -4,6 +4,11 @@ pub trait Func<Inputs> {
     type Output;
 }
 
+/// A [Func] that can also be called on values.
+pub trait FuncValue<Inputs>: Func<Inputs> {
+    fn call(&self, input: Inputs) -> Self::Output;
+}
+
 // compose maps
 
 pub struct Compose<First, Second> {
@@ -18,3 +23,13 @@ where
 {
     type Output = <Second as Func<<First as Func<Inputs>>::Output>>::Output;
 }
+
+impl<Inputs, First, Second> FuncValue<Inputs> for Compose<First, Second>
+where
+    First: FuncValue<Inputs>,
+    Second: FuncValue<<First as Func<Inputs>>::Output>,
+{
+    fn call(&self, input: Inputs) -> Self::Output {
+        self.rhs.call(self.lhs.call(input))
+    }
+}
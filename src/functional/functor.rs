@@ -0,0 +1,223 @@
+use super::Func;
+use crate::{
+    list::{Cons, FlatMap, FlatMapOp, Map as ListMap, MapEach, MapEachOp, MapOp as ListMapOp, Nil},
+    maybe::{AndThen, AndThenOp, Just, Map as MaybeMap, MapOp as MaybeMapOp, Nothing},
+};
+
+// functor
+
+/// Maps the values in a container by a [Func].
+///
+/// A [Dict](crate::dict::Dict) is a list of key-value pairs, so the function receives
+/// `(key, value)` tuples. Wrap the function in [MapValue] to map the values only.
+pub trait FMap<Function> {
+    type Output;
+}
+
+pub type FMapOp<Container, Function> = <Container as FMap<Function>>::Output;
+
+impl<Value, Function> FMap<Function> for Just<Value>
+where
+    (): MaybeMap<Self, Function>,
+{
+    type Output = MaybeMapOp<Self, Function>;
+}
+
+impl<Function> FMap<Function> for Nothing {
+    type Output = Nothing;
+}
+
+impl<Head, Tail, Function> FMap<Function> for Cons<Head, Tail>
+where
+    (): ListMap<Self, Function>,
+{
+    type Output = ListMapOp<Self, Function>;
+}
+
+impl<Function> FMap<Function> for Nil {
+    type Output = Nil;
+}
+
+// pure
+
+/// Wraps a value into the same kind of container as `Self`.
+pub trait Pure<Value> {
+    type Output;
+}
+
+pub type PureOp<Container, Value> = <Container as Pure<Value>>::Output;
+
+impl<Inner, Value> Pure<Value> for Just<Inner> {
+    type Output = Just<Value>;
+}
+
+impl<Value> Pure<Value> for Nothing {
+    type Output = Just<Value>;
+}
+
+impl<Head, Tail, Value> Pure<Value> for Cons<Head, Tail> {
+    type Output = Cons<Value, Nil>;
+}
+
+impl<Value> Pure<Value> for Nil {
+    type Output = Cons<Value, Nil>;
+}
+
+// apply
+
+/// Applies a container of [Func]s to a container of values.
+///
+/// Lists apply every function to every value, and concatenate the results in the order
+/// of the functions.
+pub trait Apply<Values> {
+    type Output;
+}
+
+pub type ApplyOp<Functions, Values> = <Functions as Apply<Values>>::Output;
+
+impl<Function, Value> Apply<Just<Value>> for Just<Function>
+where
+    Function: Func<Value>,
+{
+    type Output = Just<Function::Output>;
+}
+
+impl<Function> Apply<Nothing> for Just<Function> {
+    type Output = Nothing;
+}
+
+impl<Values> Apply<Values> for Nothing {
+    type Output = Nothing;
+}
+
+impl<Head, Tail, Values> Apply<Values> for Cons<Head, Tail>
+where
+    (): MapEach<Self, Values>,
+{
+    type Output = MapEachOp<Self, Values>;
+}
+
+impl<Values> Apply<Values> for Nil {
+    type Output = Nil;
+}
+
+// bind
+
+/// Maps the values in a container by a [Func] that returns containers, and flattens the
+/// result.
+pub trait Bind<Function> {
+    type Output;
+}
+
+pub type BindOp<Container, Function> = <Container as Bind<Function>>::Output;
+
+impl<Value, Function> Bind<Function> for Just<Value>
+where
+    (): AndThen<Self, Function>,
+{
+    type Output = AndThenOp<Self, Function>;
+}
+
+impl<Function> Bind<Function> for Nothing {
+    type Output = Nothing;
+}
+
+impl<Head, Tail, Function> Bind<Function> for Cons<Head, Tail>
+where
+    (): FlatMap<Self, Function>,
+{
+    type Output = FlatMapOp<Self, Function>;
+}
+
+impl<Function> Bind<Function> for Nil {
+    type Output = Nil;
+}
+
+// map value
+
+/// Applies the function to the value of a `(key, value)` pair.
+pub struct MapValue<Function>(pub Function);
+
+impl<Key, Value, Function> Func<(Key, Value)> for MapValue<Function>
+where
+    Function: Func<Value>,
+{
+    type Output = (Key, Function::Output);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{common::*, control::SameOp, Dict, List};
+    use typenum::consts::*;
+
+    struct Ka;
+    struct Kb;
+
+    struct PlusOneFunc;
+    impl<Value> Func<Value> for PlusOneFunc
+    where
+        Value: Add<B1>,
+    {
+        type Output = Add1<Value>;
+    }
+
+    struct DoubleFunc;
+    impl<Value> Func<Value> for DoubleFunc
+    where
+        Value: Mul<U2>,
+    {
+        type Output = Prod<Value, U2>;
+    }
+
+    struct NonZeroFunc;
+    impl Func<U0> for NonZeroFunc {
+        type Output = Nothing;
+    }
+    impl<U, B> Func<UInt<U, B>> for NonZeroFunc {
+        type Output = Just<UInt<U, B>>;
+    }
+
+    struct RepeatFunc;
+    impl<Value> Func<Value> for RepeatFunc {
+        type Output = List![Value, Value];
+    }
+
+    #[test]
+    fn functor_test() {
+        let _: SameOp<FMapOp<Just<U1>, PlusOneFunc>, Just<U2>> = ();
+        let _: SameOp<FMapOp<Nothing, PlusOneFunc>, Nothing> = ();
+        let _: SameOp<FMapOp<List![U1, U2], PlusOneFunc>, List![U2, U3]> = ();
+        let _: SameOp<FMapOp<List![], PlusOneFunc>, List![]> = ();
+        let _: SameOp<
+            FMapOp<Dict! { Ka: U1, Kb: U2 }, MapValue<PlusOneFunc>>,
+            Dict! { Ka: U2, Kb: U3 },
+        > = ();
+    }
+
+    #[test]
+    fn applicative_test() {
+        let _: SameOp<PureOp<Nothing, U1>, Just<U1>> = ();
+        let _: SameOp<PureOp<Just<U2>, U1>, Just<U1>> = ();
+        let _: SameOp<PureOp<List![], U1>, List![U1]> = ();
+        let _: SameOp<PureOp<List![U2, U3], U1>, List![U1]> = ();
+
+        let _: SameOp<ApplyOp<Just<PlusOneFunc>, Just<U1>>, Just<U2>> = ();
+        let _: SameOp<ApplyOp<Just<PlusOneFunc>, Nothing>, Nothing> = ();
+        let _: SameOp<ApplyOp<Nothing, Just<U1>>, Nothing> = ();
+        let _: SameOp<
+            ApplyOp<List![PlusOneFunc, DoubleFunc], List![U1, U2]>,
+            List![U2, U3, U2, U4],
+        > = ();
+        let _: SameOp<ApplyOp<List![], List![U1, U2]>, List![]> = ();
+    }
+
+    #[test]
+    fn monad_test() {
+        let _: SameOp<BindOp<Just<U1>, NonZeroFunc>, Just<U1>> = ();
+        let _: SameOp<BindOp<Just<U0>, NonZeroFunc>, Nothing> = ();
+        let _: SameOp<BindOp<Nothing, NonZeroFunc>, Nothing> = ();
+        let _: SameOp<BindOp<List![U1, U2], RepeatFunc>, List![U1, U1, U2, U2]> = ();
+        let _: SameOp<BindOp<List![], RepeatFunc>, List![]> = ();
+    }
+}
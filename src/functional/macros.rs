@@ -0,0 +1,131 @@
+/// Builds a [Compose](crate::functional::Compose) chain that applies the functions from
+/// left to right.
+#[macro_export]
+macro_rules! Compose {
+    [$func:ty $(,)?] => {
+        $func
+    };
+    [$func:ty, $($funcs:ty),+ $(,)?] => {
+        $crate::functional::Compose<$func, $crate::Compose![$($funcs),+]>
+    };
+}
+
+#[macro_export]
+macro_rules! compose {
+    [$func:expr $(,)?] => {
+        $func
+    };
+    [$func:expr, $($funcs:expr),+ $(,)?] => {
+        $crate::functional::Compose { lhs: $func, rhs: $crate::compose![$($funcs),+] }
+    };
+}
+
+/// Defines a zero-sized [Func](crate::functional::Func) implementor that calls a `typ!`
+/// function.
+///
+/// A function with a single argument takes the input as is, while a function with more
+/// arguments takes the inputs in a tuple, as [Fold](crate::list::Fold) does. Bounds on the
+/// arguments of the `typ!` function are repeated in the optional `where` clause.
+///
+/// ```ignore
+/// FuncOf!(pub struct PlusOneFunc = PlusOne<Value> where Value: Unsigned);
+/// FuncOf!(struct SumUpFunc = SumUp<Lhs, Rhs> where Lhs: Unsigned, Rhs: Unsigned);
+/// ```
+#[macro_export]
+macro_rules! FuncOf {
+    (
+        $vis:vis struct $name:ident = $($trait:ident)::+ <$arg:ident>
+        $(where $($bounds:tt)+)?
+    ) => {
+        $vis struct $name;
+
+        impl<$arg> $crate::functional::Func<$arg> for $name
+        where
+            (): $($trait)::+<$arg>,
+            $($($bounds)+)?
+        {
+            type Output = <() as $($trait)::+<$arg>>::Output;
+        }
+    };
+    (
+        $vis:vis struct $name:ident = $($trait:ident)::+ <$($args:ident),+ $(,)?>
+        $(where $($bounds:tt)+)?
+    ) => {
+        $vis struct $name;
+
+        impl<$($args),+> $crate::functional::Func<($($args,)+)> for $name
+        where
+            (): $($trait)::+<$($args),+>,
+            $($($bounds)+)?
+        {
+            type Output = <() as $($trait)::+<$($args),+>>::Output;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        common::*,
+        control::SameOp,
+        functional::{Compose, Func},
+        list::{FoldOp, MapOp},
+        List,
+    };
+    use typenum::consts::*;
+
+    struct A;
+    struct B;
+    struct C;
+
+    struct F;
+    impl Func<A> for F {
+        type Output = B;
+    }
+
+    struct G;
+    impl Func<B> for G {
+        type Output = C;
+    }
+
+    #[test]
+    fn compose_macros() {
+        let _: SameOp<Compose![F], F> = ();
+        let _: SameOp<Compose![F, G], Compose<F, G>> = ();
+        let _: SameOp<Compose![F, G, F], Compose<F, Compose<G, F>>> = ();
+        let _: SameOp<<Compose![F, G] as Func<A>>::Output, C> = ();
+
+        let _: Compose![F, G, F] = compose![F, G, F];
+    }
+
+    #[test]
+    fn func_of_macros() {
+        typ! {
+            fn PlusOne<value>(value: Unsigned) -> Unsigned {
+                value + 1u
+            }
+
+            fn SumUp<lhs, rhs>(lhs: Unsigned, rhs: Unsigned) -> Unsigned {
+                lhs + rhs
+            }
+
+            fn Select<cond, lhs, rhs>(cond: Bit, lhs: _, rhs: _) {
+                if cond {
+                    lhs
+                } else {
+                    rhs
+                }
+            }
+        }
+
+        FuncOf!(struct PlusOneFunc = PlusOne<Value> where Value: Unsigned);
+        FuncOf!(struct SumUpFunc = SumUp<Lhs, Rhs> where Lhs: Unsigned, Rhs: Unsigned);
+        FuncOf!(struct SelectFunc = Select<Cond, Lhs, Rhs> where Cond: Bit);
+
+        let _: SameOp<<PlusOneFunc as Func<U1>>::Output, U2> = ();
+        let _: SameOp<<SumUpFunc as Func<(U1, U2)>>::Output, U3> = ();
+        let _: SameOp<<SelectFunc as Func<(B0, A, B)>>::Output, B> = ();
+        let _: SameOp<MapOp<List![U1, U2, U3], PlusOneFunc>, List![U2, U3, U4]> = ();
+        let _: SameOp<FoldOp<List![U1, U2, U3], U4, SumUpFunc>, U10> = ();
+    }
+}
@@ -1,3 +1,7 @@
 mod base;
+mod functor;
+mod macros;
+pub mod prelude;
 
 pub use base::*;
+pub use functor::*;
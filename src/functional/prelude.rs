@@ -0,0 +1,420 @@
+//! Ready-made [Func] implementors to build pipelines without helper functions.
+
+use super::{Func, FuncValue, Pure, PureOp};
+use crate::{
+    common::*,
+    list::{Cons, Nil},
+    maybe::{Just, Nothing},
+};
+use typenum::{IsGreater, IsLess};
+
+// identity
+
+/// Returns the input.
+pub struct Identity;
+
+impl<Input> Func<Input> for Identity {
+    type Output = Input;
+}
+
+impl<Input> FuncValue<Input> for Identity {
+    fn call(&self, input: Input) -> Self::Output {
+        input
+    }
+}
+
+// const
+
+/// Returns `Value` regardless of the input.
+pub struct Const<Value>(pub Value);
+
+impl<Value, Input> Func<Input> for Const<Value> {
+    type Output = Value;
+}
+
+impl<Value, Input> FuncValue<Input> for Const<Value>
+where
+    Value: Clone,
+{
+    fn call(&self, _input: Input) -> Self::Output {
+        self.0.clone()
+    }
+}
+
+// flip
+
+/// Calls the binary function with swapped `(lhs, rhs)` inputs.
+pub struct Flip<Function>(pub Function);
+
+impl<Function, Lhs, Rhs> Func<(Lhs, Rhs)> for Flip<Function>
+where
+    Function: Func<(Rhs, Lhs)>,
+{
+    type Output = Function::Output;
+}
+
+impl<Function, Lhs, Rhs> FuncValue<(Lhs, Rhs)> for Flip<Function>
+where
+    Function: FuncValue<(Rhs, Lhs)>,
+{
+    fn call(&self, (lhs, rhs): (Lhs, Rhs)) -> Self::Output {
+        self.0.call((rhs, lhs))
+    }
+}
+
+// partial application
+
+/// Calls the binary function with `Arg` as the first input.
+pub struct Partial<Function, Arg>(pub Function, pub Arg);
+
+impl<Function, Arg, Input> Func<Input> for Partial<Function, Arg>
+where
+    Function: Func<(Arg, Input)>,
+{
+    type Output = Function::Output;
+}
+
+impl<Function, Arg, Input> FuncValue<Input> for Partial<Function, Arg>
+where
+    Function: FuncValue<(Arg, Input)>,
+    Arg: Clone,
+{
+    fn call(&self, input: Input) -> Self::Output {
+        self.0.call((self.1.clone(), input))
+    }
+}
+
+/// Turns the binary function to a function that returns a [Partial].
+pub struct Curry<Function>(pub Function);
+
+impl<Function, Input> Func<Input> for Curry<Function> {
+    type Output = Partial<Function, Input>;
+}
+
+impl<Function, Input> FuncValue<Input> for Curry<Function>
+where
+    Function: Clone,
+{
+    fn call(&self, input: Input) -> Self::Output {
+        Partial(self.0.clone(), input)
+    }
+}
+
+// arithmetic
+
+/// Adds `Rhs` to the input.
+pub struct AddBy<Rhs>(pub Rhs);
+
+impl<Rhs, Input> Func<Input> for AddBy<Rhs>
+where
+    Input: Add<Rhs>,
+{
+    type Output = Sum<Input, Rhs>;
+}
+
+impl<Rhs, Input> FuncValue<Input> for AddBy<Rhs>
+where
+    Input: Add<Rhs>,
+    Rhs: Clone,
+{
+    fn call(&self, input: Input) -> Self::Output {
+        input + self.0.clone()
+    }
+}
+
+/// Multiplies the input by `Rhs`.
+pub struct MulBy<Rhs>(pub Rhs);
+
+impl<Rhs, Input> Func<Input> for MulBy<Rhs>
+where
+    Input: Mul<Rhs>,
+{
+    type Output = Prod<Input, Rhs>;
+}
+
+impl<Rhs, Input> FuncValue<Input> for MulBy<Rhs>
+where
+    Input: Mul<Rhs>,
+    Rhs: Clone,
+{
+    fn call(&self, input: Input) -> Self::Output {
+        input * self.0.clone()
+    }
+}
+
+/// Adds the `(lhs, rhs)` inputs.
+pub struct Plus;
+
+impl<Lhs, Rhs> Func<(Lhs, Rhs)> for Plus
+where
+    Lhs: Add<Rhs>,
+{
+    type Output = Sum<Lhs, Rhs>;
+}
+
+impl<Lhs, Rhs> FuncValue<(Lhs, Rhs)> for Plus
+where
+    Lhs: Add<Rhs>,
+{
+    fn call(&self, (lhs, rhs): (Lhs, Rhs)) -> Self::Output {
+        lhs + rhs
+    }
+}
+
+/// Multiplies the `(lhs, rhs)` inputs.
+pub struct Times;
+
+impl<Lhs, Rhs> Func<(Lhs, Rhs)> for Times
+where
+    Lhs: Mul<Rhs>,
+{
+    type Output = Prod<Lhs, Rhs>;
+}
+
+impl<Lhs, Rhs> FuncValue<(Lhs, Rhs)> for Times
+where
+    Lhs: Mul<Rhs>,
+{
+    fn call(&self, (lhs, rhs): (Lhs, Rhs)) -> Self::Output {
+        lhs * rhs
+    }
+}
+
+// comparison
+
+/// Returns a [Bit] telling whether comparing the input to `Rhs` gives `Order`, which is one
+/// of [Less], [Equal] or [Greater].
+pub struct CmpWith<Rhs, Order>(pub Rhs, pub Order);
+
+impl<Rhs, Input> Func<Input> for CmpWith<Rhs, Less>
+where
+    Input: IsLess<Rhs>,
+{
+    type Output = <Input as IsLess<Rhs>>::Output;
+}
+
+impl<Rhs, Input> FuncValue<Input> for CmpWith<Rhs, Less>
+where
+    Input: IsLess<Rhs>,
+{
+    fn call(&self, _input: Input) -> Self::Output {
+        Default::default()
+    }
+}
+
+impl<Rhs, Input> Func<Input> for CmpWith<Rhs, Equal>
+where
+    Input: IsEqual<Rhs>,
+{
+    type Output = <Input as IsEqual<Rhs>>::Output;
+}
+
+impl<Rhs, Input> FuncValue<Input> for CmpWith<Rhs, Equal>
+where
+    Input: IsEqual<Rhs>,
+{
+    fn call(&self, _input: Input) -> Self::Output {
+        Default::default()
+    }
+}
+
+impl<Rhs, Input> Func<Input> for CmpWith<Rhs, Greater>
+where
+    Input: IsGreater<Rhs>,
+{
+    type Output = <Input as IsGreater<Rhs>>::Output;
+}
+
+impl<Rhs, Input> FuncValue<Input> for CmpWith<Rhs, Greater>
+where
+    Input: IsGreater<Rhs>,
+{
+    fn call(&self, _input: Input) -> Self::Output {
+        Default::default()
+    }
+}
+
+// boolean
+
+/// Negates the input [Bit].
+pub struct Not;
+
+impl<Input> Func<Input> for Not
+where
+    Input: Bit + std::ops::Not,
+{
+    type Output = <Input as std::ops::Not>::Output;
+}
+
+impl<Input> FuncValue<Input> for Not
+where
+    Input: Bit + std::ops::Not,
+{
+    fn call(&self, input: Input) -> Self::Output {
+        !input
+    }
+}
+
+/// Returns the conjunction of the [Bit]s returned by both functions.
+pub struct And<Lhs, Rhs>(pub Lhs, pub Rhs);
+
+impl<Lhs, Rhs, Input> Func<Input> for And<Lhs, Rhs>
+where
+    Lhs: Func<Input>,
+    Rhs: Func<Input>,
+    Lhs::Output: BitAnd<Rhs::Output>,
+{
+    type Output = <Lhs::Output as BitAnd<Rhs::Output>>::Output;
+}
+
+impl<Lhs, Rhs, Input> FuncValue<Input> for And<Lhs, Rhs>
+where
+    Lhs: FuncValue<Input>,
+    Rhs: FuncValue<Input>,
+    Lhs::Output: BitAnd<Rhs::Output>,
+    Input: Clone,
+{
+    fn call(&self, input: Input) -> Self::Output {
+        self.0.call(input.clone()) & self.1.call(input)
+    }
+}
+
+/// Returns the disjunction of the [Bit]s returned by both functions.
+pub struct Or<Lhs, Rhs>(pub Lhs, pub Rhs);
+
+impl<Lhs, Rhs, Input> Func<Input> for Or<Lhs, Rhs>
+where
+    Lhs: Func<Input>,
+    Rhs: Func<Input>,
+    Lhs::Output: BitOr<Rhs::Output>,
+{
+    type Output = <Lhs::Output as BitOr<Rhs::Output>>::Output;
+}
+
+impl<Lhs, Rhs, Input> FuncValue<Input> for Or<Lhs, Rhs>
+where
+    Lhs: FuncValue<Input>,
+    Rhs: FuncValue<Input>,
+    Lhs::Output: BitOr<Rhs::Output>,
+    Input: Clone,
+{
+    fn call(&self, input: Input) -> Self::Output {
+        self.0.call(input.clone()) | self.1.call(input)
+    }
+}
+
+// wrap
+
+/// Wraps the input into the same kind of container as `Container` by [Pure].
+///
+/// For example, `Wrap<Nothing>` returns [Just](crate::maybe::Just) and `Wrap<Nil>` returns
+/// a single-item list.
+pub struct Wrap<Container>(pub Container);
+
+impl<Container, Input> Func<Input> for Wrap<Container>
+where
+    Container: Pure<Input>,
+{
+    type Output = PureOp<Container, Input>;
+}
+
+impl<Input> FuncValue<Input> for Wrap<Nothing> {
+    fn call(&self, input: Input) -> Self::Output {
+        Just(input)
+    }
+}
+
+impl<Value, Input> FuncValue<Input> for Wrap<Just<Value>> {
+    fn call(&self, input: Input) -> Self::Output {
+        Just(input)
+    }
+}
+
+impl<Input> FuncValue<Input> for Wrap<Nil> {
+    fn call(&self, input: Input) -> Self::Output {
+        Cons {
+            head: input,
+            tail: Nil,
+        }
+    }
+}
+
+impl<Head, Tail, Input> FuncValue<Input> for Wrap<Cons<Head, Tail>> {
+    fn call(&self, input: Input) -> Self::Output {
+        Cons {
+            head: input,
+            tail: Nil,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        control::SameOp,
+        list,
+        list::{FilterOp, FoldOp, MapOp, ValueMap},
+        Compose, List,
+    };
+    use typenum::consts::*;
+
+    struct A;
+    struct B;
+
+    struct SubFunc;
+    impl<Lhs, Rhs> Func<(Lhs, Rhs)> for SubFunc
+    where
+        Lhs: Sub<Rhs>,
+    {
+        type Output = Diff<Lhs, Rhs>;
+    }
+
+    #[test]
+    fn prelude_test() {
+        let _: SameOp<<Identity as Func<A>>::Output, A> = ();
+        let _: SameOp<<Const<B> as Func<A>>::Output, B> = ();
+        let _: SameOp<<Flip<SubFunc> as Func<(U2, U5)>>::Output, U3> = ();
+        let _: SameOp<<Partial<SubFunc, U5> as Func<U2>>::Output, U3> = ();
+        let _: SameOp<<Curry<SubFunc> as Func<U5>>::Output, Partial<SubFunc, U5>> = ();
+        let _: SameOp<<Not as Func<B0>>::Output, B1> = ();
+        let _: SameOp<<Plus as Func<(U2, U5)>>::Output, U7> = ();
+        let _: SameOp<<Times as Func<(U2, U5)>>::Output, U10> = ();
+    }
+
+    #[test]
+    fn prelude_pipeline_test() {
+        let _: SameOp<MapOp<List![U1, U2, U3], AddBy<U1>>, List![U2, U3, U4]> = ();
+        let _: SameOp<MapOp<List![U1, U2, U3], Compose![AddBy<U1>, MulBy<U2>]>, List![U4, U6, U8]> =
+            ();
+        let _: SameOp<MapOp<List![A, B], Wrap<Nothing>>, List![Just<A>, Just<B>]> = ();
+        let _: SameOp<MapOp<List![A, B], Wrap<Nil>>, List![List![A], List![B]]> = ();
+        let _: SameOp<MapOp<List![A, B], Const<U0>>, List![U0, U0]> = ();
+
+        let _: SameOp<FilterOp<List![P1, Z0, N2, P3], CmpWith<Z0, Greater>>, List![P1, P3]> = ();
+        let _: SameOp<
+            FilterOp<List![P1, Z0, N2, P3], Compose![CmpWith<Z0, Equal>, Not]>,
+            List![P1, N2, P3],
+        > = ();
+        let _: SameOp<
+            FilterOp<List![P1, Z0, N2, P3], And<CmpWith<Z0, Greater>, CmpWith<P3, Less>>>,
+            List![P1],
+        > = ();
+        let _: SameOp<
+            FilterOp<List![P1, Z0, N2, P3], Or<CmpWith<Z0, Less>, CmpWith<P3, Equal>>>,
+            List![N2, P3],
+        > = ();
+
+        let _: SameOp<FoldOp<List![U1, U2, U3], U10, SubFunc>, U4> = ();
+        let _: SameOp<FoldOp<List![U1, U2], U0, Flip<SubFunc>>, U1> = ();
+    }
+    #[test]
+    fn prelude_value_test() {
+        let Just(value) = Wrap(Nothing).call(3u8);
+        assert_eq!(value, 3);
+        assert_eq!(Wrap(Nil).call(3u8), list![3u8]);
+
+        let wrapped = list![1u8, "a"].map(&Wrap(Nil));
+        assert_eq!(wrapped, list![list![1u8], list!["a"]]);
+    }
+}
@@ -1,4 +1,6 @@
+pub mod array;
 pub mod bitset;
+pub mod bounded;
 pub mod common;
 pub mod control;
 pub mod dict;
@@ -7,7 +9,9 @@ pub mod floating;
 pub mod fraction;
 pub mod functional;
 pub mod list;
+pub mod literal;
 pub mod maybe;
 pub mod numeric;
+pub mod shape;
 pub mod stepper;
 pub mod tuple;
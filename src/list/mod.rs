@@ -2,6 +2,8 @@ mod base;
 mod convert;
 mod macros;
 mod ops;
+mod value;
 
 pub use base::*;
 pub use ops::*;
+pub use value::*;
@@ -393,6 +393,35 @@ typ! {
         }
     }
 
+    pub fn ZipWith<lhs, rhs, func>(lhs: List, rhs: List, func: _) -> List {
+        let zipped: List = Zip(lhs, rhs);
+        Map(zipped, func)
+    }
+
+    pub fn FlatMap<list, func>(list: List, func: _) -> List {
+        match list {
+            #[generics(head, tail: List)]
+            Cons::<head, tail> => {
+                let new_head: List = func.Func(head);
+                let new_tail = FlatMap(tail, func);
+                Extend(new_head, new_tail)
+            }
+            Nil => Nil,
+        }
+    }
+
+    pub fn MapEach<funcs, list>(funcs: List, list: List) -> List {
+        match funcs {
+            #[generics(func, tail: List)]
+            Cons::<func, tail> => {
+                let mapped = Map(list, func);
+                let rest = MapEach(tail, list);
+                Extend(mapped, rest)
+            }
+            Nil => Nil,
+        }
+    }
+
     pub fn Fold<list, init, func>(list: List, init: _, func: _) {
         match list {
             #[generics(head, tail: List)]
@@ -658,4 +687,40 @@ mod tests {
 
         let _: SameOp<FilterMapOp<List![P1, Z0, N2, Z0], FlipNegativeFunc>, List![P2]> = ();
     }
+
+    #[test]
+    fn mixed_list_test() {
+        use crate::{dyn_::Dyn, functional::prelude::Plus};
+
+        let _: SameOp<ReduceSumOp<List![U3, Dyn<usize>, U4]>, Dyn<usize>> = ();
+        let _: SameOp<ReduceProductOp<List![U3, Dyn<usize>, U4]>, Dyn<usize>> = ();
+        let _: SameOp<ReduceProductOp<List![U3, U4]>, U12> = ();
+        let _: SameOp<ReduceMaxOp<List![Dyn<usize>, U4]>, Dyn<usize>> = ();
+        let _: SameOp<
+            ZipWithOp<List![U3, Dyn<usize>, U4], List![U1, U2, Dyn<usize>], Plus>,
+            List![U4, Dyn<usize>, Dyn<usize>],
+        > = ();
+        let _: SameOp<ExtendOp<List![U3, Dyn<usize>], List![U4]>, List![U3, Dyn<usize>, U4]> = ();
+    }
+
+    #[test]
+    fn flat_map_test() {
+        struct RepeatFunc;
+        impl<Value> Func<Value> for RepeatFunc {
+            type Output = List![Value, Value];
+        }
+
+        struct WrapFunc;
+        impl<Value> Func<Value> for WrapFunc {
+            type Output = Just<Value>;
+        }
+
+        let _: SameOp<FlatMapOp<List![], RepeatFunc>, List![]> = ();
+        let _: SameOp<FlatMapOp<List![A, B], RepeatFunc>, List![A, A, B, B]> = ();
+        let _: SameOp<MapEachOp<List![], List![A, B]>, List![]> = ();
+        let _: SameOp<
+            MapEachOp<List![WrapFunc, RepeatFunc], List![A, B]>,
+            List![Just<A>, Just<B>, List![A, A], List![B, B]],
+        > = ();
+    }
 }
@@ -0,0 +1,646 @@
+//! Value-level drivers of list operators that call [FuncValue] on `list!` values.
+
+use super::{Cons, List, Nil};
+use crate::{
+    common::*,
+    dyn_::{IntoStaticError, ToUsize, TryIntoStatic},
+    functional::FuncValue,
+};
+use std::{error::Error, fmt};
+
+// map
+
+/// Calls the function on every item, matching the output type of [Map](super::Map).
+pub trait ValueMap<Function>
+where
+    Self: List,
+{
+    type Output: List;
+
+    fn map(self, func: &Function) -> Self::Output;
+}
+
+impl<Head, Tail, Function> ValueMap<Function> for Cons<Head, Tail>
+where
+    Tail: List + ValueMap<Function>,
+    Function: FuncValue<Head>,
+{
+    type Output = Cons<Function::Output, Tail::Output>;
+
+    fn map(self, func: &Function) -> Self::Output {
+        let Cons { head, tail } = self;
+        Cons {
+            head: func.call(head),
+            tail: tail.map(func),
+        }
+    }
+}
+
+impl<Function> ValueMap<Function> for Nil {
+    type Output = Nil;
+
+    fn map(self, _func: &Function) -> Self::Output {
+        Nil
+    }
+}
+
+// fold
+
+/// Folds the items by a function on `(init, item)` pairs, matching the output type of
+/// [Fold](super::Fold).
+pub trait ValueFold<Init, Function>
+where
+    Self: List,
+{
+    type Output;
+
+    fn fold(self, init: Init, func: &Function) -> Self::Output;
+}
+
+impl<Head, Tail, Init, Function> ValueFold<Init, Function> for Cons<Head, Tail>
+where
+    Tail: List + ValueFold<Function::Output, Function>,
+    Function: FuncValue<(Init, Head)>,
+{
+    type Output = Tail::Output;
+
+    fn fold(self, init: Init, func: &Function) -> Self::Output {
+        let Cons { head, tail } = self;
+        tail.fold(func.call((init, head)), func)
+    }
+}
+
+impl<Init, Function> ValueFold<Init, Function> for Nil {
+    type Output = Init;
+
+    fn fold(self, init: Init, _func: &Function) -> Self::Output {
+        init
+    }
+}
+
+// filter
+
+/// Keeps the items on which the function returns [B1], matching the output type of
+/// [Filter](super::Filter).
+pub trait ValueFilter<Function>
+where
+    Self: List,
+{
+    type Output: List;
+
+    fn filter(self, func: &Function) -> Self::Output;
+}
+
+impl<Head, Tail, Function> ValueFilter<Function> for Cons<Head, Tail>
+where
+    Tail: List + ValueFilter<Function>,
+    Function: FuncValue<Head>,
+    Function::Output: FilterStep<Head, Tail::Output>,
+{
+    type Output = <Function::Output as FilterStep<Head, Tail::Output>>::Output;
+
+    fn filter(self, func: &Function) -> Self::Output {
+        let Cons { head, tail } = self;
+        let tail = tail.filter(func);
+        <Function::Output as FilterStep<Head, Tail::Output>>::step(head, tail)
+    }
+}
+
+impl<Function> ValueFilter<Function> for Nil {
+    type Output = Nil;
+
+    fn filter(self, _func: &Function) -> Self::Output {
+        Nil
+    }
+}
+
+#[doc(hidden)]
+pub trait FilterStep<Head, Tail>
+where
+    Tail: List,
+{
+    type Output: List;
+
+    fn step(head: Head, tail: Tail) -> Self::Output;
+}
+
+impl<Head, Tail> FilterStep<Head, Tail> for B1
+where
+    Tail: List,
+{
+    type Output = Cons<Head, Tail>;
+
+    fn step(head: Head, tail: Tail) -> Self::Output {
+        Cons { head, tail }
+    }
+}
+
+impl<Head, Tail> FilterStep<Head, Tail> for B0
+where
+    Tail: List,
+{
+    type Output = Tail;
+
+    fn step(_head: Head, tail: Tail) -> Self::Output {
+        tail
+    }
+}
+
+// get
+
+/// Borrows the item at a static index, matching the output type of
+/// [GetByUnsigned](super::GetByUnsigned).
+pub trait ValueGet<Index>
+where
+    Self: List,
+{
+    type Output;
+
+    fn get(&self) -> &Self::Output;
+}
+
+impl<Head, Tail> ValueGet<UTerm> for Cons<Head, Tail>
+where
+    Tail: List,
+{
+    type Output = Head;
+
+    fn get(&self) -> &Self::Output {
+        &self.head
+    }
+}
+
+impl<Head, Tail, U, B> ValueGet<UInt<U, B>> for Cons<Head, Tail>
+where
+    Tail: List + ValueGet<Sub1<UInt<U, B>>>,
+    UInt<U, B>: Sub<B1>,
+{
+    type Output = <Tail as ValueGet<Sub1<UInt<U, B>>>>::Output;
+
+    fn get(&self) -> &Self::Output {
+        self.tail.get()
+    }
+}
+
+// remove
+
+/// Removes the item at a static index, matching the output type of [Remove](super::Remove).
+pub trait ValueRemove<Index>
+where
+    Self: List,
+{
+    type Output: List;
+
+    fn remove(self) -> Self::Output;
+}
+
+impl<Head, Tail> ValueRemove<UTerm> for Cons<Head, Tail>
+where
+    Tail: List,
+{
+    type Output = Tail;
+
+    fn remove(self) -> Self::Output {
+        self.tail
+    }
+}
+
+impl<Head, Tail, U, B> ValueRemove<UInt<U, B>> for Cons<Head, Tail>
+where
+    Tail: List + ValueRemove<Sub1<UInt<U, B>>>,
+    UInt<U, B>: Sub<B1>,
+{
+    type Output = Cons<Head, <Tail as ValueRemove<Sub1<UInt<U, B>>>>::Output>;
+
+    fn remove(self) -> Self::Output {
+        Cons {
+            head: self.head,
+            tail: self.tail.remove(),
+        }
+    }
+}
+
+// reverse
+
+/// Reverses the items, matching the output type of [Reverse](super::Reverse).
+pub trait ValueReverse
+where
+    Self: List,
+{
+    type Output: List;
+
+    fn reverse(self) -> Self::Output;
+}
+
+impl<Input> ValueReverse for Input
+where
+    Input: List + ReverseOnto<Nil>,
+{
+    type Output = <Input as ReverseOnto<Nil>>::Output;
+
+    fn reverse(self) -> Self::Output {
+        self.reverse_onto(Nil)
+    }
+}
+
+#[doc(hidden)]
+pub trait ReverseOnto<Saved>
+where
+    Self: List,
+    Saved: List,
+{
+    type Output: List;
+
+    fn reverse_onto(self, saved: Saved) -> Self::Output;
+}
+
+impl<Head, Tail, Saved> ReverseOnto<Saved> for Cons<Head, Tail>
+where
+    Tail: List + ReverseOnto<Cons<Head, Saved>>,
+    Saved: List,
+{
+    type Output = <Tail as ReverseOnto<Cons<Head, Saved>>>::Output;
+
+    fn reverse_onto(self, saved: Saved) -> Self::Output {
+        self.tail.reverse_onto(Cons {
+            head: self.head,
+            tail: saved,
+        })
+    }
+}
+
+impl<Saved> ReverseOnto<Saved> for Nil
+where
+    Saved: List,
+{
+    type Output = Saved;
+
+    fn reverse_onto(self, saved: Saved) -> Self::Output {
+        saved
+    }
+}
+
+// zip
+
+/// Pairs up the items of two lists of the same length, matching the output type of
+/// [Zip](super::Zip).
+pub trait ValueZip<Rhs>
+where
+    Self: List,
+    Rhs: List,
+{
+    type Output: List;
+
+    fn zip(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<LHead, LTail, RHead, RTail> ValueZip<Cons<RHead, RTail>> for Cons<LHead, LTail>
+where
+    LTail: List + ValueZip<RTail>,
+    RTail: List,
+{
+    type Output = Cons<(LHead, RHead), LTail::Output>;
+
+    fn zip(self, rhs: Cons<RHead, RTail>) -> Self::Output {
+        Cons {
+            head: (self.head, rhs.head),
+            tail: self.tail.zip(rhs.tail),
+        }
+    }
+}
+
+impl ValueZip<Nil> for Nil {
+    type Output = Nil;
+
+    fn zip(self, _rhs: Nil) -> Self::Output {
+        Nil
+    }
+}
+
+// extend
+
+/// Appends a list, matching the output type of [Extend](super::Extend).
+pub trait ValueExtend<Rhs>
+where
+    Self: List,
+    Rhs: List,
+{
+    type Output: List;
+
+    fn extend(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<Head, Tail, Rhs> ValueExtend<Rhs> for Cons<Head, Tail>
+where
+    Tail: List + ValueExtend<Rhs>,
+    Rhs: List,
+{
+    type Output = Cons<Head, Tail::Output>;
+
+    fn extend(self, rhs: Rhs) -> Self::Output {
+        Cons {
+            head: self.head,
+            tail: self.tail.extend(rhs),
+        }
+    }
+}
+
+impl<Rhs> ValueExtend<Rhs> for Nil
+where
+    Rhs: List,
+{
+    type Output = Rhs;
+
+    fn extend(self, rhs: Rhs) -> Self::Output {
+        rhs
+    }
+}
+
+// reduction
+
+/// Sums up the items, matching the output type of [ReduceSum](super::ReduceSum).
+pub trait ValueReduceSum
+where
+    Self: List,
+{
+    type Output;
+
+    fn reduce_sum(self) -> Self::Output;
+}
+
+impl<Head> ValueReduceSum for Cons<Head, Nil> {
+    type Output = Head;
+
+    fn reduce_sum(self) -> Self::Output {
+        self.head
+    }
+}
+
+impl<Head1, Head2, Tail> ValueReduceSum for Cons<Head1, Cons<Head2, Tail>>
+where
+    Tail: List,
+    Cons<Head2, Tail>: ValueReduceSum,
+    Head1: Add<<Cons<Head2, Tail> as ValueReduceSum>::Output>,
+{
+    type Output = Sum<Head1, <Cons<Head2, Tail> as ValueReduceSum>::Output>;
+
+    fn reduce_sum(self) -> Self::Output {
+        self.head + self.tail.reduce_sum()
+    }
+}
+
+/// Multiplies the items, matching the output type of [ReduceProduct](super::ReduceProduct).
+pub trait ValueReduceProduct
+where
+    Self: List,
+{
+    type Output;
+
+    fn reduce_product(self) -> Self::Output;
+}
+
+impl<Head> ValueReduceProduct for Cons<Head, Nil> {
+    type Output = Head;
+
+    fn reduce_product(self) -> Self::Output {
+        self.head
+    }
+}
+
+impl<Head1, Head2, Tail> ValueReduceProduct for Cons<Head1, Cons<Head2, Tail>>
+where
+    Tail: List,
+    Cons<Head2, Tail>: ValueReduceProduct,
+    Head1: Mul<<Cons<Head2, Tail> as ValueReduceProduct>::Output>,
+{
+    type Output = Prod<Head1, <Cons<Head2, Tail> as ValueReduceProduct>::Output>;
+
+    fn reduce_product(self) -> Self::Output {
+        self.head * self.tail.reduce_product()
+    }
+}
+
+// runtime values
+
+/// Reads a list of static [Unsigned]s and `Dyn<usize>`s as runtime `usize`s.
+pub trait ToUsizeVec
+where
+    Self: List,
+{
+    fn to_usize_vec(&self) -> Vec<usize>;
+}
+
+impl<Head, Tail> ToUsizeVec for Cons<Head, Tail>
+where
+    Head: ToUsize,
+    Tail: List + ToUsizeVec,
+{
+    fn to_usize_vec(&self) -> Vec<usize> {
+        let mut output = vec![self.head.to_usize()];
+        output.extend(self.tail.to_usize_vec());
+        output
+    }
+}
+
+impl ToUsizeVec for Nil {
+    fn to_usize_vec(&self) -> Vec<usize> {
+        vec![]
+    }
+}
+
+// cast
+
+/// The error when an item of a list cannot be cast to the static item at `index`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListMismatch<T> {
+    pub index: usize,
+    pub error: IntoStaticError<T>,
+}
+
+impl<T> fmt::Display for ListMismatch<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at index {}: {}", self.index, self.error)
+    }
+}
+
+impl<T> Error for ListMismatch<T> where T: fmt::Debug {}
+
+/// Casts a list of static and `Dyn<T>` items to the fully static list `Target`,
+/// checking the dynamic items at runtime.
+pub trait TryCast<Target, T>
+where
+    Self: List,
+    Target: List,
+{
+    fn try_cast(self) -> Result<Target, ListMismatch<T>>;
+
+    #[doc(hidden)]
+    fn try_cast_from(self, index: usize) -> Result<Target, ListMismatch<T>>;
+}
+
+impl<Head, Tail, TargetHead, TargetTail, T> TryCast<Cons<TargetHead, TargetTail>, T>
+    for Cons<Head, Tail>
+where
+    Head: TryIntoStatic<TargetHead, T>,
+    Tail: List + TryCast<TargetTail, T>,
+    TargetTail: List,
+{
+    fn try_cast(self) -> Result<Cons<TargetHead, TargetTail>, ListMismatch<T>> {
+        self.try_cast_from(0)
+    }
+
+    fn try_cast_from(self, index: usize) -> Result<Cons<TargetHead, TargetTail>, ListMismatch<T>> {
+        let head = self
+            .head
+            .try_into_static()
+            .map_err(|error| ListMismatch { index, error })?;
+        let tail = self.tail.try_cast_from(index + 1)?;
+        Ok(Cons { head, tail })
+    }
+}
+
+impl<T> TryCast<Nil, T> for Nil {
+    fn try_cast(self) -> Result<Nil, ListMismatch<T>> {
+        Ok(Nil)
+    }
+
+    fn try_cast_from(self, _index: usize) -> Result<Nil, ListMismatch<T>> {
+        Ok(Nil)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        compose,
+        dyn_::{Dyn, Mismatch},
+        functional::{
+            prelude::{AddBy, CmpWith, MulBy, Plus},
+            Func,
+        },
+        list, List,
+    };
+    use typenum::consts::*;
+
+    struct ToStringFunc;
+    impl<Value> Func<Value> for ToStringFunc {
+        type Output = String;
+    }
+    impl<Value> FuncValue<Value> for ToStringFunc
+    where
+        Value: std::fmt::Debug,
+    {
+        fn call(&self, input: Value) -> Self::Output {
+            format!("{:?}", input)
+        }
+    }
+
+    struct SumUpFunc;
+    impl<Lhs, Rhs> Func<(Lhs, Rhs)> for SumUpFunc
+    where
+        Lhs: Add<Rhs>,
+    {
+        type Output = Lhs::Output;
+    }
+    impl<Lhs, Rhs> FuncValue<(Lhs, Rhs)> for SumUpFunc
+    where
+        Lhs: Add<Rhs>,
+    {
+        fn call(&self, (lhs, rhs): (Lhs, Rhs)) -> Self::Output {
+            lhs + rhs
+        }
+    }
+
+    #[test]
+    fn value_map_test() {
+        let output = list![1u8, 'a', Some(2)].map(&ToStringFunc);
+        assert_eq!(
+            output,
+            list!["1".to_string(), "'a'".to_string(), "Some(2)".to_string()]
+        );
+
+        let output = list![1usize, 2].map(&compose![AddBy(1usize), MulBy(2usize)]);
+        assert_eq!(output, list![4, 6]);
+
+        let _: List![U4, U6] =
+            list![U1::new(), U2::new()].map(&compose![AddBy(U1::new()), MulBy(U2::new())]);
+        let _: Nil = list![].map(&ToStringFunc);
+    }
+
+    #[test]
+    fn value_fold_test() {
+        assert_eq!(list![1usize, 2, 3].fold(4usize, &SumUpFunc), 10);
+        assert_eq!(list![].fold(4usize, &SumUpFunc), 4);
+
+        let _: U10 = list![U1::new(), U2::new(), U3::new()].fold(U4::new(), &SumUpFunc);
+    }
+
+    #[test]
+    fn value_filter_test() {
+        let _: List![P1, P3] =
+            list![P1::new(), Z0::new(), N2::new(), P3::new()].filter(&CmpWith(Z0::new(), Greater));
+        let _: Nil = list![].filter(&CmpWith(Z0::new(), Greater));
+    }
+
+    #[test]
+    fn value_get_test() {
+        let list = list![1u8, "a", 2.5f32];
+        assert_eq!(*ValueGet::<U0>::get(&list), 1u8);
+        assert_eq!(*ValueGet::<U1>::get(&list), "a");
+        assert_eq!(*ValueGet::<U2>::get(&list), 2.5f32);
+    }
+
+    #[test]
+    fn value_remove_reverse_test() {
+        let list: List![u8, &str, f32] = list![1u8, "a", 2.5f32];
+        assert_eq!(ValueRemove::<U1>::remove(list), list![1u8, 2.5f32]);
+        assert_eq!(ValueRemove::<U0>::remove(list), list!["a", 2.5f32]);
+        let reversed: List![f32, &str, u8] = list.reverse();
+        assert_eq!(reversed, list![2.5f32, "a", 1u8]);
+        assert_eq!(list![].reverse(), list![]);
+    }
+
+    #[test]
+    fn value_mixed_list_test() {
+        let shape = list![U3::new(), Dyn(5usize), U4::new()];
+        let _: Dyn<usize> = shape.reduce_sum();
+        assert_eq!(shape.reduce_sum(), Dyn(12));
+        assert_eq!(shape.reduce_product(), Dyn(60));
+        assert_eq!(shape.to_usize_vec(), vec![3, 5, 4]);
+
+        let _: U12 = list![U3::new(), U4::new()].reduce_product();
+
+        let sum = list![U3::new(), Dyn(5usize), U4::new()]
+            .zip(list![U1::new(), U2::new(), Dyn(6usize)])
+            .map(&Plus);
+        let _: List![U4, Dyn<usize>, Dyn<usize>] = sum;
+        assert_eq!(sum.to_usize_vec(), vec![4, 7, 10]);
+
+        let shape = list![U3::new(), Dyn(5usize)].extend(list![U4::new()]);
+        let _: List![U3, Dyn<usize>, U4] = shape;
+        assert_eq!(shape.to_usize_vec(), vec![3, 5, 4]);
+    }
+
+    #[test]
+    fn value_try_cast_test() {
+        let shape: List![U3, U5, U4] = list![U3::new(), Dyn(5usize), U4::new()].try_cast().unwrap();
+        assert_eq!(shape, list![U3::new(), U5::new(), U4::new()]);
+
+        let result: Result<List![U3, U5, U4], _> =
+            list![Dyn(3usize), U5::new(), Dyn(2usize)].try_cast();
+        assert_eq!(
+            result,
+            Err(ListMismatch {
+                index: 2,
+                error: IntoStaticError::Mismatch(Mismatch {
+                    expected: 4,
+                    actual: 2
+                })
+            })
+        );
+    }
+}
@@ -0,0 +1,331 @@
+//! Compile-time parsing of numeric literals used by the construction macros.
+
+use crate::common::*;
+
+// const bit
+
+/// Converts a `const` boolean to a typed bit.
+#[doc(hidden)]
+pub struct ConstBit<const BIT: bool>;
+
+#[doc(hidden)]
+pub trait ToBit {
+    type Output: Bit;
+}
+
+impl ToBit for ConstBit<false> {
+    type Output = B0;
+}
+
+impl ToBit for ConstBit<true> {
+    type Output = B1;
+}
+
+// parsing
+
+/// Returns the bit at `index` of `value`.
+#[doc(hidden)]
+pub const fn bit(value: u64, index: u32) -> bool {
+    (value >> index) & 1 == 1
+}
+
+/// Returns `10` to the power of `exp`.
+#[doc(hidden)]
+pub const fn pow10(exp: u32) -> u64 {
+    let mut output: u64 = 1;
+    let mut count = 0;
+    while count < exp {
+        output = match output.checked_mul(10) {
+            Some(output) => output,
+            None => panic!("unsupported literal: the value overflows u64"),
+        };
+        count += 1;
+    }
+    output
+}
+
+/// Checks that the literal is a decimal integer or float literal without a radix prefix
+/// or a type suffix.
+const fn validate(lit: &str) {
+    let bytes = lit.as_bytes();
+    if bytes.is_empty() || !bytes[0].is_ascii_digit() {
+        panic!("unsupported literal: expect a decimal literal");
+    }
+
+    let mut index = 0;
+    let mut after_point = false;
+    while index < bytes.len() && bytes[index] != b'e' && bytes[index] != b'E' {
+        let byte = bytes[index];
+        if byte == b'.' && !after_point {
+            after_point = true;
+        } else if !byte.is_ascii_digit() && byte != b'_' {
+            panic!("unsupported literal: expect a decimal literal without prefix or suffix");
+        }
+        index += 1;
+    }
+    if index == bytes.len() {
+        return;
+    }
+    index += 1;
+
+    if index < bytes.len() && (bytes[index] == b'-' || bytes[index] == b'+') {
+        index += 1;
+    }
+    let mut has_digits = false;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if byte.is_ascii_digit() {
+            has_digits = true;
+        } else if byte != b'_' {
+            panic!("unsupported literal: expect a decimal literal without prefix or suffix");
+        }
+        index += 1;
+    }
+    if !has_digits {
+        panic!("unsupported literal: expect digits in the exponent");
+    }
+}
+
+/// Returns all decimal digits of the literal before the exponent part.
+///
+/// For example, `1.25e-3` gives `125`.
+#[doc(hidden)]
+pub const fn significand(lit: &str) -> u64 {
+    validate(lit);
+    let bytes = lit.as_bytes();
+    let mut output: u64 = 0;
+    let mut index = 0;
+
+    while index < bytes.len() && bytes[index] != b'e' && bytes[index] != b'E' {
+        let byte = bytes[index];
+        if byte.is_ascii_digit() {
+            output = match output.checked_mul(10) {
+                Some(output) => match output.checked_add((byte - b'0') as u64) {
+                    Some(output) => output,
+                    None => panic!("unsupported literal: the digits overflow u64"),
+                },
+                None => panic!("unsupported literal: the digits overflow u64"),
+            };
+        }
+        index += 1;
+    }
+
+    output
+}
+
+/// Returns the number of digits after the decimal point.
+///
+/// For example, `1.25e-3` gives `2`.
+#[doc(hidden)]
+pub const fn scale(lit: &str) -> u32 {
+    validate(lit);
+    let bytes = lit.as_bytes();
+    let mut output = 0;
+    let mut index = 0;
+    let mut after_point = false;
+
+    while index < bytes.len() && bytes[index] != b'e' && bytes[index] != b'E' {
+        let byte = bytes[index];
+        if byte == b'.' {
+            after_point = true;
+        } else if byte.is_ascii_digit() && after_point {
+            output += 1;
+        }
+        index += 1;
+    }
+
+    output
+}
+
+/// Returns the exponent after `e` or `E`, or zero if the literal has no exponent.
+///
+/// For example, `1.25e-3` gives `-3`.
+#[doc(hidden)]
+pub const fn exponent(lit: &str) -> i32 {
+    validate(lit);
+    let bytes = lit.as_bytes();
+    let mut index = 0;
+
+    while index < bytes.len() && bytes[index] != b'e' && bytes[index] != b'E' {
+        index += 1;
+    }
+    if index == bytes.len() {
+        return 0;
+    }
+    index += 1;
+
+    let mut negative = false;
+    if bytes[index] == b'-' || bytes[index] == b'+' {
+        negative = bytes[index] == b'-';
+        index += 1;
+    }
+
+    let mut output: i32 = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if byte.is_ascii_digit() {
+            output = match output.checked_mul(10) {
+                Some(output) => match output.checked_add((byte - b'0') as i32) {
+                    Some(output) => output,
+                    None => panic!("unsupported literal: the exponent overflows i32"),
+                },
+                None => panic!("unsupported literal: the exponent overflows i32"),
+            };
+        }
+        index += 1;
+    }
+
+    if negative {
+        -output
+    } else {
+        output
+    }
+}
+
+/// Returns the numerator of the decimal literal written as a fraction of power of ten.
+///
+/// For example, `1.25e-3` gives `125` and `1.25e3` gives `1250`.
+#[doc(hidden)]
+pub const fn numerator(lit: &str) -> u64 {
+    let exp = exponent(lit) - scale(lit) as i32;
+    if exp >= 0 {
+        match significand(lit).checked_mul(pow10(exp as u32)) {
+            Some(output) => output,
+            None => panic!("unsupported literal: the value overflows u64"),
+        }
+    } else {
+        significand(lit)
+    }
+}
+
+/// Returns the denominator of the decimal literal written as a fraction of power of ten.
+///
+/// For example, `1.25e-3` gives `100000` and `1.25e3` gives `1`.
+#[doc(hidden)]
+pub const fn denominator(lit: &str) -> u64 {
+    let exp = exponent(lit) - scale(lit) as i32;
+    if exp >= 0 {
+        1
+    } else {
+        pow10((-exp) as u32)
+    }
+}
+
+/// Returns the exponent of the decimal literal when its significand is written as an integer.
+///
+/// For example, `1.25e-3` gives `-5` and `1.25e3` gives `1`.
+#[doc(hidden)]
+pub const fn float_exponent(lit: &str) -> i64 {
+    exponent(lit) as i64 - scale(lit) as i64
+}
+
+// macros
+
+/// Builds a typenum [Unsigned] from a `const` `u64` expression.
+#[macro_export]
+macro_rules! ConstUnsigned {
+    (@bits $value:expr; $($index:literal)*) => {
+        $crate::bitset::ToUnsignedOp<
+            $crate::List![
+                $(
+                    <$crate::literal::ConstBit<{ $crate::literal::bit($value, $index) }>
+                        as $crate::literal::ToBit>::Output
+                ),*
+            ]
+        >
+    };
+    ($value:expr) => {
+        $crate::ConstUnsigned!(
+            @bits $value;
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+            16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+            32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+            48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+        )
+    };
+}
+
+/// Builds a typenum [Integer] from a `const` `i64` expression.
+#[macro_export]
+macro_rules! ConstInteger {
+    ($value:expr) => {
+        $crate::numeric::ToIntegerOp<
+            $crate::ConstUnsigned!(($value as i64).unsigned_abs()),
+            <$crate::literal::ConstBit<{ ($value as i64) < 0 }> as $crate::literal::ToBit>::Output,
+        >
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control::SameOp;
+    use typenum::consts::*;
+
+    #[test]
+    fn literal_test() {
+        assert_eq!(significand("0.125"), 125);
+        assert_eq!(significand("1_000"), 1000);
+        assert_eq!(significand("1.25e-3"), 125);
+        assert_eq!(scale("3"), 0);
+        assert_eq!(scale("0.125"), 3);
+        assert_eq!(scale("1.25e-3"), 2);
+        assert_eq!(exponent("0.125"), 0);
+        assert_eq!(exponent("1.25e-3"), -3);
+        assert_eq!(exponent("1.25E+3"), 3);
+        assert_eq!(numerator("0.125"), 125);
+        assert_eq!(denominator("0.125"), 1000);
+        assert_eq!(numerator("1.25e3"), 1250);
+        assert_eq!(denominator("1.25e3"), 1);
+        assert_eq!(numerator("1.25e-3"), 125);
+        assert_eq!(denominator("1.25e-3"), 100000);
+        assert_eq!(float_exponent("1.25e-3"), -5);
+        assert_eq!(float_exponent("1.25e3"), 1);
+        assert_eq!(float_exponent("3"), 0);
+
+        let _: SameOp<ConstUnsigned!(0), U0> = ();
+        let _: SameOp<ConstUnsigned!(1), U1> = ();
+        let _: SameOp<ConstUnsigned!(125 * 3), U375> = ();
+        let _: SameOp<ConstUnsigned!(significand("0.125")), U125> = ();
+        let _: SameOp<ConstUnsigned!(pow10(6)), U1000000> = ();
+        let _: SameOp<ConstInteger!(0), Z0> = ();
+        let _: SameOp<ConstInteger!(12), P12> = ();
+        let _: SameOp<ConstInteger!(float_exponent("1.25e-3")), N5> = ();
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported literal")]
+    fn hex_literal_test() {
+        significand("0x10");
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported literal")]
+    fn binary_literal_test() {
+        significand("0b11");
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported literal")]
+    fn suffixed_literal_test() {
+        scale("0.5f64");
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported literal")]
+    fn suffixed_integer_literal_test() {
+        exponent("1u8");
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported literal")]
+    fn overflowing_literal_test() {
+        significand("123456789012345678901");
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported literal")]
+    fn overflowing_exponent_test() {
+        numerator("1e20");
+    }
+}
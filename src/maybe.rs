@@ -12,7 +12,7 @@ mod base {
     // just def
 
     /// A type analogous to `Some`.
-    pub struct Just<T>(T);
+    pub struct Just<T>(pub T);
 
     impl<T> Maybe for Just<T> {}
 
@@ -66,6 +66,14 @@ mod ops {
                 Nothing => Nothing
             }
         }
+
+        pub fn AndThen<maybe, func>(maybe: Maybe, func: _) -> Maybe {
+            match maybe {
+                #[generics(value)]
+                Just::<value> => func.Func(value),
+                Nothing => Nothing
+            }
+        }
     }
 }
 
@@ -4,6 +4,62 @@ use crate::common::*;
 
 pub use ops::*;
 
+// float conversion
+
+/// Converts a type-level number to a primitive float.
+pub trait ToFloat {
+    fn to_f64() -> f64;
+
+    /// Rounds through `f64` by default, which rounds twice and can be one ulp off.
+    ///
+    /// Decimal [Float](crate::floating::Float)s override it to round once. Base-2 floats
+    /// with significands of at most 53 bits and fractions whose terms fit in 24 bits also
+    /// round once, as their `f64` values are exact or close enough.
+    fn to_f32() -> f32 {
+        Self::to_f64() as f32
+    }
+}
+
+impl ToFloat for UTerm {
+    fn to_f64() -> f64 {
+        0.0
+    }
+}
+
+impl<U, B> ToFloat for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+{
+    fn to_f64() -> f64 {
+        Self::U128 as f64
+    }
+}
+
+impl ToFloat for Z0 {
+    fn to_f64() -> f64 {
+        0.0
+    }
+}
+
+impl<U> ToFloat for PInt<U>
+where
+    U: Unsigned + NonZero,
+{
+    fn to_f64() -> f64 {
+        Self::I128 as f64
+    }
+}
+
+impl<U> ToFloat for NInt<U>
+where
+    U: Unsigned + NonZero,
+{
+    fn to_f64() -> f64 {
+        Self::I128 as f64
+    }
+}
+
 mod ops {
     use super::*;
 
@@ -61,6 +117,21 @@ mod ops {
             (lhs - (lhs % rhs)) / rhs
         }
 
+        pub fn ToInteger<value, negative>(value: Unsigned, negative: Bit) -> Integer {
+            match value {
+                UTerm => Z0,
+                #[generics(uint: Unsigned, bit: Bit)]
+                UInt::<uint, bit> => {
+                    let value: Unsigned + NonZero = value;
+                    if negative {
+                        NInt::<value>
+                    } else {
+                        PInt::<value>
+                    }
+                }
+            }
+        }
+
         pub fn SignedIntegerDiv<lhs, rhs>(lhs: Integer, rhs: Integer + NonZero) -> Integer {
             match (lhs, rhs) {
                 #[generics(lint: Unsigned + NonZero, rint: Unsigned + NonZero)]
@@ -148,5 +219,8 @@ mod tests {
         let _: SameOp<GcdOp<U1, U1>, U1> = ();
         let _: SameOp<GcdOp<U84, U126>, U42> = ();
         let _: SameOp<LcmOp<U84, U126>, U252> = ();
+        let _: SameOp<ToIntegerOp<U0, B1>, Z0> = ();
+        let _: SameOp<ToIntegerOp<U3, B0>, P3> = ();
+        let _: SameOp<ToIntegerOp<U3, B1>, N3> = ();
     }
 }
@@ -0,0 +1,85 @@
+use crate::common::*;
+
+/// A placeholder dimension in the target shape of [ResolveReshape](super::ResolveReshape),
+/// like `-1` in `view(-1, ...)`.
+pub struct Infer;
+
+/// A range from `From` to `To`, exclusive, taking every `Step`-th item, for
+/// [SliceShape](super::SliceShape).
+///
+/// ```rust
+/// use type_freak::{control::SameOp, shape::{SliceShapeOp, StepRange}, List};
+/// use typenum::consts::*;
+///
+/// let _: SameOp<SliceShapeOp<List![U8], List![StepRange<U1, U8, U2>]>, List![U4]> = ();
+/// ```
+///
+/// A zero `Step` fails to compile.
+///
+/// ```compile_fail
+/// use type_freak::{control::SameOp, shape::{SliceShapeOp, StepRange}, List};
+/// use typenum::consts::*;
+///
+/// let _: SameOp<SliceShapeOp<List![U8], List![StepRange<U1, U8, U0>]>, List![U0]> = ();
+/// ```
+pub struct StepRange<From, To, Step>(PhantomData<(From, To, Step)>);
+
+/// An axis label of [Einsum](super::Einsum), identified by a typenum [Unsigned].
+///
+/// Typenum integers and [Char!](crate::Char) are labels of themselves. Einsum compares
+/// labels by their ids, so every other label type needs a manual impl that picks an id
+/// distinct from the other labels in use. Einsum fails to compile if two distinct labels
+/// share an id.
+///
+/// ```rust
+/// use type_freak::{control::SameOp, shape::{EinsumOp, LabelId}, List};
+/// use typenum::consts::*;
+///
+/// struct Batch;
+/// struct Feature;
+///
+/// impl LabelId for Batch {
+///     type Output = U0;
+/// }
+///
+/// impl LabelId for Feature {
+///     type Output = U1;
+/// }
+///
+/// type Output = EinsumOp<List![List![Batch, Feature]], List![Feature], List![List![U8, U3]]>;
+/// let _: SameOp<Output, List![U3]> = ();
+/// ```
+///
+/// ```compile_fail
+/// use type_freak::{control::SameOp, shape::{EinsumOp, LabelId}, List};
+/// use typenum::consts::*;
+///
+/// struct Batch;
+/// struct Feature;
+///
+/// impl LabelId for Batch {
+///     type Output = U0;
+/// }
+///
+/// impl LabelId for Feature {
+///     type Output = U0;
+/// }
+///
+/// type Output = EinsumOp<List![List![Batch, Feature]], List![Feature], List![List![U8, U8]]>;
+/// let _: SameOp<Output, List![U8]> = ();
+/// ```
+pub trait LabelId {
+    type Output: Unsigned;
+}
+
+impl LabelId for UTerm {
+    type Output = UTerm;
+}
+
+impl<U, B> LabelId for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+{
+    type Output = UInt<U, B>;
+}
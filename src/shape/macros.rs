@@ -0,0 +1,23 @@
+/// Builds a permutation [List](crate::list::List) from index literals, or applies it to a
+/// list type.
+///
+/// `Permute![2, 0, 1]` gives `List![U2, U0, U1]`, and `Permute![List![A, B, C]; 2, 0, 1]`
+/// gives `List![C, A, B]`.
+#[macro_export]
+macro_rules! Permute {
+    [$($index:literal),* $(,)?] => {
+        $crate::List![$(typ::tyuint!($index)),*]
+    };
+    [$list:ty; $($index:literal),* $(,)?] => {
+        $crate::shape::PermApplyOp<$crate::Permute![$($index),*], $list>
+    };
+}
+
+/// Builds a type-level char from a char literal, which is the typenum
+/// [Unsigned](typenum::Unsigned) of its code point.
+#[macro_export]
+macro_rules! Char {
+    ($value:literal) => {
+        $crate::ConstUnsigned!($value as u64)
+    };
+}
@@ -0,0 +1,10 @@
+//! Tensor shape operators over a [List](crate::list::List) of typenum [Unsigned](typenum::Unsigned) dimensions.
+
+mod base;
+mod macros;
+mod ops;
+mod value;
+
+pub use base::*;
+pub use ops::*;
+pub use value::*;
@@ -0,0 +1,817 @@
+use super::{Infer, LabelId, StepRange};
+use crate::{
+    common::*,
+    control::{AssertLessOrEqual, AssertSame},
+    dict::{Dict, GetUnsigned as DictGetUnsigned, Insert as DictInsert},
+    list::{Cons, GetByUnsigned, Insert, Len, List, Nil, ReduceProduct, Remove, Reverse, ZipEx},
+    maybe::{Just, Maybe, Nothing},
+    numeric::UnsignedIntegerDiv,
+};
+use typenum::{U0, U1};
+
+typ! {
+    pub fn NumElements<shape>(shape: List) -> Unsigned {
+        match shape {
+            #[generics(head, tail: List)]
+            Cons::<head, tail> => ReduceProduct(shape),
+            Nil => 1u,
+        }
+    }
+
+    pub fn CanReshape<from, to>(from: List, to: List) -> Bit {
+        let from_elements: Unsigned = NumElements(from);
+        let to_elements: Unsigned = NumElements(to);
+        from_elements == to_elements
+    }
+
+    // reshape with inferred dimension
+
+    pub fn ResolveReshape<from, to>(from: List, to: List) -> List {
+        let total: Unsigned = NumElements(from);
+        let known: Unsigned = KnownElements(to);
+        let count: Unsigned = CountInfer(to);
+
+        if count == 0u {
+            SameDim(total, known);
+            to
+        } else {
+            SameDim(count, U1);
+            let remainder: Unsigned = total % known;
+            SameDim(remainder, U0);
+            let missing: Unsigned = UnsignedIntegerDiv(total, known);
+            ReplaceInfer(to, missing)
+        }
+    }
+
+    fn KnownElements<shape>(shape: List) -> Unsigned {
+        match shape {
+            #[generics(head, tail: List)]
+            Cons::<head, tail> => {
+                let dim: Unsigned = InferDim(head, U1);
+                let rest: Unsigned = KnownElements(tail);
+                dim * rest
+            }
+            Nil => 1u,
+        }
+    }
+
+    fn CountInfer<shape>(shape: List) -> Unsigned {
+        match shape {
+            #[generics(head, tail: List)]
+            Cons::<head, tail> => {
+                let count: Unsigned = IsInfer(head);
+                let rest: Unsigned = CountInfer(tail);
+                count + rest
+            }
+            Nil => 0u,
+        }
+    }
+
+    fn ReplaceInfer<shape, value>(shape: List, value: Unsigned) -> List {
+        match shape {
+            #[generics(head, tail: List)]
+            Cons::<head, tail> => {
+                let new_head: Unsigned = InferDim(head, value);
+                let new_tail: List = ReplaceInfer(tail, value);
+                Cons::<new_head, new_tail>
+            }
+            Nil => Nil,
+        }
+    }
+
+    fn IsInfer<dim>(dim: _) -> Unsigned {
+        match dim {
+            Infer => 1u,
+            UTerm => 0u,
+            #[generics(uint: Unsigned, bit: Bit)]
+            UInt::<uint, bit> => 0u,
+        }
+    }
+
+    fn InferDim<dim, value>(dim: _, value: Unsigned) -> Unsigned {
+        match dim {
+            Infer => value,
+            UTerm => UTerm,
+            #[generics(uint: Unsigned, bit: Bit)]
+            UInt::<uint, bit> => {
+                let dim: Unsigned = dim;
+                dim
+            }
+        }
+    }
+
+    // broadcast
+
+    pub fn Broadcast<lhs, rhs>(lhs: List, rhs: List) -> List {
+        let lhs_rev: List = Reverse(lhs);
+        let rhs_rev: List = Reverse(rhs);
+        let output_rev: List = BroadcastReversed(lhs_rev, rhs_rev);
+        Reverse(output_rev)
+    }
+
+    fn BroadcastReversed<lhs, rhs>(lhs: List, rhs: List) -> List {
+        match (lhs, rhs) {
+            #[generics(lhead: Unsigned, ltail: List, rhead: Unsigned, rtail: List)]
+            (Cons::<lhead, ltail>, Cons::<rhead, rtail>) => {
+                let dim: Unsigned = BroadcastDim(lhead, rhead);
+                let tail: List = BroadcastReversed(ltail, rtail);
+                Cons::<dim, tail>
+            }
+            #[generics(lhead, ltail: List)]
+            (Cons::<lhead, ltail>, Nil) => lhs,
+            #[generics(rhead, rtail: List)]
+            (Nil, Cons::<rhead, rtail>) => rhs,
+            (Nil, Nil) => Nil,
+        }
+    }
+
+    pub fn BroadcastDim<lhs, rhs>(lhs: Unsigned, rhs: Unsigned) -> Unsigned {
+        if lhs == 1u {
+            rhs
+        } else if rhs == 1u {
+            lhs
+        } else {
+            SameDim(lhs, rhs)
+        }
+    }
+
+    fn SameDim<lhs, rhs>(lhs: Unsigned, rhs: Unsigned) -> Unsigned {
+        AssertSame(lhs, rhs);
+        lhs
+    }
+
+    // matrix multiplication
+
+    pub fn MatMulShape<lhs, rhs>(lhs: List, rhs: List) -> List {
+        let lhs_rev: List = Reverse(lhs);
+        let rhs_rev: List = Reverse(rhs);
+        let output_rev: List = MatMulReversed(lhs_rev, rhs_rev);
+        Reverse(output_rev)
+    }
+
+    fn MatMulReversed<lhs, rhs>(lhs: List, rhs: List) -> List {
+        match (lhs, rhs) {
+            #[generics(lk: Unsigned, rk: Unsigned)]
+            (Cons::<lk, Nil>, Cons::<rk, Nil>) => {
+                SameDim(lk, rk);
+                Nil
+            }
+            #[generics(lk: Unsigned, m, rk: Unsigned, rbatch: List)]
+            (Cons::<lk, Nil>, Cons::<m, Cons::<rk, rbatch>>) => {
+                SameDim(lk, rk);
+                Cons::<m, rbatch>
+            }
+            #[generics(lk: Unsigned, n, lbatch: List, rk: Unsigned)]
+            (Cons::<lk, Cons::<n, lbatch>>, Cons::<rk, Nil>) => {
+                SameDim(lk, rk);
+                Cons::<n, lbatch>
+            }
+            #[generics(lk: Unsigned, n, lbatch: List, m, rk: Unsigned, rbatch: List)]
+            (Cons::<lk, Cons::<n, lbatch>>, Cons::<m, Cons::<rk, rbatch>>) => {
+                SameDim(lk, rk);
+                let batch: List = BroadcastReversed(lbatch, rbatch);
+                Cons::<m, Cons<n, batch>>
+            }
+        }
+    }
+
+    // axis manipulation
+
+    pub fn Squeeze<shape, axis>(shape: List, axis: Unsigned) -> List {
+        let dim: Unsigned = GetByUnsigned(shape, axis);
+        SameDim(dim, U1);
+        Remove(shape, axis)
+    }
+
+    pub fn Unsqueeze<shape, axis>(shape: List, axis: Unsigned) -> List {
+        Insert(shape, axis, U1)
+    }
+
+    pub fn ConcatShape<lhs, rhs, axis>(lhs: List, rhs: List, axis: Unsigned) -> List {
+        match (lhs, rhs, axis) {
+            #[generics(lhead: Unsigned, rhead: Unsigned, tail: List)]
+            (Cons::<lhead, tail>, Cons::<rhead, tail>, UTerm) => {
+                let dim: Unsigned = lhead + rhead;
+                Cons::<dim, tail>
+            }
+            #[generics(head, ltail: List, rtail: List, uint: Unsigned, bit: Bit)]
+            (Cons::<head, ltail>, Cons::<head, rtail>, UInt::<uint, bit>) => {
+                let new_axis: Unsigned = axis - 1u;
+                let new_tail: List = ConcatShape(ltail, rtail, new_axis);
+                Cons::<head, new_tail>
+            }
+        }
+    }
+
+    // convolution
+
+    pub fn ConvOutputDim<input, kernel, stride, padding, dilation>(input: Unsigned, kernel: Unsigned, stride: Unsigned + NonZero, padding: Unsigned, dilation: Unsigned) -> Unsigned {
+        let padded: Unsigned = input + padding * 2u;
+        let span: Unsigned = KernelSpan(kernel, dilation);
+        let remaining: Unsigned = padded - span;
+        let steps: Unsigned = UnsignedIntegerDiv(remaining, stride);
+        steps + 1u
+    }
+
+    pub fn ConvTransposeOutputDim<input, kernel, stride, padding, dilation, output_padding>(input: Unsigned, kernel: Unsigned, stride: Unsigned, padding: Unsigned, dilation: Unsigned, output_padding: Unsigned) -> Unsigned {
+        let steps: Unsigned = input - 1u;
+        let span: Unsigned = KernelSpan(kernel, dilation);
+        let grown: Unsigned = steps * stride + span + output_padding;
+        grown - padding * 2u
+    }
+
+    pub fn SamePadding<input, kernel, stride, dilation>(input: Unsigned, kernel: Unsigned, stride: Unsigned + NonZero, dilation: Unsigned) -> Unsigned {
+        let rounded: Unsigned = input + stride - 1u;
+        let output: Unsigned = UnsignedIntegerDiv(rounded, stride);
+        let steps: Unsigned = output - 1u;
+        let span: Unsigned = KernelSpan(kernel, dilation);
+        let needed: Unsigned = steps * stride + span;
+        if needed > input {
+            let total: Unsigned = needed - input;
+            let rounded_total: Unsigned = total + 1u;
+            let padding: Unsigned = rounded_total / 2u;
+            let padded_output: Unsigned = ConvOutputDim(input, kernel, stride, padding, dilation);
+            SameDim(padded_output, output);
+            padding
+        } else {
+            0u
+        }
+    }
+
+    fn KernelSpan<kernel, dilation>(kernel: Unsigned, dilation: Unsigned) -> Unsigned {
+        let gaps: Unsigned = kernel - 1u;
+        dilation * gaps + 1u
+    }
+
+    pub fn ConvOutputShape<input, kernel, stride, padding, dilation>(input: List, kernel: List, stride: List, padding: List, dilation: List) -> List {
+        let params = Cons::<input, Cons<kernel, Cons<stride, Cons<padding, Cons<dilation, Nil>>>>>;
+        let zipped: List = ZipEx(params);
+        ConvOutputShapeRecursive(zipped)
+    }
+
+    fn ConvOutputShapeRecursive<params>(params: List) -> List {
+        match params {
+            #[generics(input: Unsigned, kernel: Unsigned, stride: Unsigned, padding: Unsigned, dilation: Unsigned, tail: List)]
+            Cons::<Cons::<input, Cons::<kernel, Cons::<stride, Cons::<padding, Cons::<dilation, Nil>>>>>, tail> => {
+                let dim: Unsigned = ConvOutputDim(input, kernel, stride, padding, dilation);
+                let new_tail: List = ConvOutputShapeRecursive(tail);
+                Cons::<dim, new_tail>
+            }
+            Nil => Nil,
+        }
+    }
+
+    pub fn ConvTransposeOutputShape<input, kernel, stride, padding, dilation, output_padding>(input: List, kernel: List, stride: List, padding: List, dilation: List, output_padding: List) -> List {
+        let params = Cons::<input, Cons<kernel, Cons<stride, Cons<padding, Cons<dilation, Cons<output_padding, Nil>>>>>>;
+        let zipped: List = ZipEx(params);
+        ConvTransposeOutputShapeRecursive(zipped)
+    }
+
+    fn ConvTransposeOutputShapeRecursive<params>(params: List) -> List {
+        match params {
+            #[generics(input: Unsigned, kernel: Unsigned, stride: Unsigned, padding: Unsigned, dilation: Unsigned, output_padding: Unsigned, tail: List)]
+            Cons::<Cons::<input, Cons::<kernel, Cons::<stride, Cons::<padding, Cons::<dilation, Cons::<output_padding, Nil>>>>>>, tail> => {
+                let dim: Unsigned = ConvTransposeOutputDim(input, kernel, stride, padding, dilation, output_padding);
+                let new_tail: List = ConvTransposeOutputShapeRecursive(tail);
+                Cons::<dim, new_tail>
+            }
+            Nil => Nil,
+        }
+    }
+
+    // slicing
+
+    pub fn SliceShape<shape, ranges>(shape: List, ranges: List) -> List {
+        match (shape, ranges) {
+            #[generics(dim: Unsigned, shape_tail: List, range, ranges_tail: List)]
+            (Cons::<dim, shape_tail>, Cons::<range, ranges_tail>) => {
+                let new_dim: Unsigned = SliceDim(dim, range);
+                let new_tail: List = SliceShape(shape_tail, ranges_tail);
+                Cons::<new_dim, new_tail>
+            }
+            #[capture(shape)]
+            (shape, Nil) => shape,
+        }
+    }
+
+    fn SliceDim<dim, range>(dim: Unsigned, range: _) -> Unsigned {
+        match range {
+            #[generics(from: Unsigned, to: Unsigned)]
+            Range::<(from, to)> => {
+                AssertLessOrEqual(to, dim);
+                AssertLessOrEqual(from, to);
+                to - from
+            }
+            #[generics(from: Unsigned, to: Unsigned)]
+            RangeInclusive::<(from, to)> => {
+                let end: Unsigned = to + 1u;
+                AssertLessOrEqual(end, dim);
+                AssertLessOrEqual(from, end);
+                end - from
+            }
+            #[generics(from: Unsigned)]
+            RangeFrom::<from> => {
+                AssertLessOrEqual(from, dim);
+                dim - from
+            }
+            #[generics(to: Unsigned)]
+            RangeTo::<to> => {
+                AssertLessOrEqual(to, dim);
+                to
+            }
+            #[generics(to: Unsigned)]
+            RangeToInclusive::<to> => {
+                let end: Unsigned = to + 1u;
+                AssertLessOrEqual(end, dim);
+                end
+            }
+            RangeFull => dim,
+            #[generics(from: Unsigned, to: Unsigned, step: Unsigned + NonZero)]
+            StepRange::<from, to, step> => {
+                AssertLessOrEqual(to, dim);
+                AssertLessOrEqual(from, to);
+                let len: Unsigned = to - from;
+                let rounded: Unsigned = len + step - 1u;
+                UnsignedIntegerDiv(rounded, step)
+            }
+        }
+    }
+
+    // strides
+
+    pub fn RowMajorStrides<shape>(shape: List) -> List {
+        let reversed: List = Reverse(shape);
+        let strides_rev: List = ColMajorStrides(reversed);
+        Reverse(strides_rev)
+    }
+
+    pub fn ColMajorStrides<shape>(shape: List) -> List {
+        ColMajorStridesRecursive(shape, U1)
+    }
+
+    fn ColMajorStridesRecursive<shape, stride>(shape: List, stride: _) -> List {
+        match shape {
+            #[generics(dim, tail: List)]
+            Cons::<dim, tail> => {
+                let next_stride = stride * dim;
+                let new_tail: List = ColMajorStridesRecursive(tail, next_stride);
+                Cons::<stride, new_tail>
+            }
+            Nil => Nil,
+        }
+    }
+
+    pub fn IsContiguous<shape, strides>(shape: List, strides: List) -> Bit {
+        let expected: List = RowMajorStrides(shape);
+        IsContiguousRecursive(shape, expected, strides)
+    }
+
+    fn IsContiguousRecursive<shape, expected, strides>(shape: List, expected: List, strides: List) -> Bit {
+        match (shape, expected, strides) {
+            #[generics(dim: Unsigned, shape_tail: List, expected_stride: Unsigned, expected_tail: List, stride: Unsigned, strides_tail: List)]
+            (Cons::<dim, shape_tail>, Cons::<expected_stride, expected_tail>, Cons::<stride, strides_tail>) => {
+                if dim == 1u {
+                    IsContiguousRecursive(shape_tail, expected_tail, strides_tail)
+                } else if expected_stride == stride {
+                    IsContiguousRecursive(shape_tail, expected_tail, strides_tail)
+                } else {
+                    false
+                }
+            }
+            (Nil, Nil, Nil) => true,
+        }
+    }
+
+    pub fn LinearOffset<strides, index>(strides: List, index: List) {
+        match (strides, index) {
+            #[generics(stride, strides_tail: List, position, index_tail: List)]
+            (Cons::<stride, strides_tail>, Cons::<position, index_tail>) => {
+                let rest = LinearOffset(strides_tail, index_tail);
+                stride * position + rest
+            }
+            (Nil, Nil) => 0u,
+        }
+    }
+
+    // permutation
+
+    pub fn IsPermutation<perm>(perm: List) -> Bit {
+        let len: Unsigned = Len(perm);
+        IsPermutationRecursive(perm, len)
+    }
+
+    fn IsPermutationRecursive<perm, len>(perm: List, len: Unsigned) -> Bit {
+        match perm {
+            #[generics(index: Unsigned, tail: List)]
+            Cons::<index, tail> => {
+                if index < len {
+                    let duplicated: Bit = ContainsIndex(tail, index);
+                    if duplicated {
+                        false
+                    } else {
+                        IsPermutationRecursive(tail, len)
+                    }
+                } else {
+                    false
+                }
+            }
+            Nil => true,
+        }
+    }
+
+    fn ContainsIndex<list, index>(list: List, index: Unsigned) -> Bit {
+        match list {
+            #[generics(head: Unsigned, tail: List)]
+            Cons::<head, tail> => {
+                if head == index {
+                    true
+                } else {
+                    ContainsIndex(tail, index)
+                }
+            }
+            Nil => false,
+        }
+    }
+
+    fn AssertPermutation<perm>(perm: List) {
+        let valid: Bit = IsPermutation(perm);
+        match valid {
+            B1 => (),
+        }
+    }
+
+    pub fn PermApply<perm, list>(perm: List, list: List) -> List {
+        AssertPermutation(perm);
+        let perm_len: Unsigned = Len(perm);
+        let list_len: Unsigned = Len(list);
+        SameDim(perm_len, list_len);
+        PermApplyRecursive(perm, list)
+    }
+
+    fn PermApplyRecursive<perm, list>(perm: List, list: List) -> List {
+        match perm {
+            #[generics(index: Unsigned, tail: List)]
+            Cons::<index, tail> => {
+                let item = GetByUnsigned(list, index);
+                let new_tail: List = PermApplyRecursive(tail, list);
+                Cons::<item, new_tail>
+            }
+            Nil => Nil,
+        }
+    }
+
+    pub fn PermInverse<perm>(perm: List) -> List {
+        AssertPermutation(perm);
+        let len: Unsigned = Len(perm);
+        PermInverseRecursive(perm, U0, len)
+    }
+
+    fn PermInverseRecursive<perm, index, len>(perm: List, index: Unsigned, len: Unsigned) -> List {
+        if index == len {
+            Nil
+        } else {
+            let position: Unsigned = PositionOf(perm, index);
+            let next_index: Unsigned = index + 1u;
+            let tail: List = PermInverseRecursive(perm, next_index, len);
+            Cons::<position, tail>
+        }
+    }
+
+    fn PositionOf<list, index>(list: List, index: Unsigned) -> Unsigned {
+        match list {
+            #[generics(head: Unsigned, tail: List)]
+            Cons::<head, tail> => {
+                if head == index {
+                    0u
+                } else {
+                    PositionOf(tail, index) + 1u
+                }
+            }
+        }
+    }
+
+    pub fn PermCompose<first, second>(first: List, second: List) -> List {
+        PermApply(second, first)
+    }
+
+    pub fn Argsort<list>(list: List) -> List {
+        let ranks: List = Ranks(Nil, list);
+        PermInverse(ranks)
+    }
+
+    fn Ranks<before, remaining>(before: List, remaining: List) -> List {
+        match remaining {
+            #[generics(item: Unsigned, tail: List)]
+            Cons::<item, tail> => {
+                let before_count: Unsigned = CountNotGreater(before, item);
+                let after_count: Unsigned = CountLess(tail, item);
+                let rank: Unsigned = before_count + after_count;
+                let new_before = Cons::<item, before>;
+                let new_tail: List = Ranks(new_before, tail);
+                Cons::<rank, new_tail>
+            }
+            Nil => Nil,
+        }
+    }
+
+    fn CountLess<list, value>(list: List, value: Unsigned) -> Unsigned {
+        match list {
+            #[generics(head: Unsigned, tail: List)]
+            Cons::<head, tail> => {
+                let rest: Unsigned = CountLess(tail, value);
+                if head < value {
+                    rest + 1u
+                } else {
+                    rest
+                }
+            }
+            Nil => 0u,
+        }
+    }
+
+    fn CountNotGreater<list, value>(list: List, value: Unsigned) -> Unsigned {
+        match list {
+            #[generics(head: Unsigned, tail: List)]
+            Cons::<head, tail> => {
+                let rest: Unsigned = CountNotGreater(tail, value);
+                if head > value {
+                    rest
+                } else {
+                    rest + 1u
+                }
+            }
+            Nil => 0u,
+        }
+    }
+
+    pub fn Transpose<shape, perm>(shape: List, perm: List) -> List {
+        PermApply(perm, shape)
+    }
+
+    // einsum
+
+    pub fn Einsum<inputs, output, shapes>(inputs: List, output: List, shapes: List) -> List {
+        let sizes: Dict = EinsumSizes(Nil, inputs, shapes);
+        EinsumOutput(sizes, output)
+    }
+
+    fn EinsumSizes<sizes, inputs, shapes>(sizes: Dict, inputs: List, shapes: List) -> Dict {
+        match (inputs, shapes) {
+            #[generics(labels: List, inputs_tail: List, shape: List, shapes_tail: List)]
+            (Cons::<labels, inputs_tail>, Cons::<shape, shapes_tail>) => {
+                let new_sizes: Dict = EinsumOperandSizes(sizes, labels, shape);
+                EinsumSizes(new_sizes, inputs_tail, shapes_tail)
+            }
+            (Nil, Nil) => sizes,
+        }
+    }
+
+    fn EinsumOperandSizes<sizes, labels, shape>(sizes: Dict, labels: List, shape: List) -> Dict {
+        match (labels, shape) {
+            #[generics(label: LabelId, labels_tail: List, dim: Unsigned, shape_tail: List)]
+            (Cons::<label, labels_tail>, Cons::<dim, shape_tail>) => {
+                let new_sizes: Dict = EinsumInsertSize(sizes, label, dim);
+                EinsumOperandSizes(new_sizes, labels_tail, shape_tail)
+            }
+            (Nil, Nil) => sizes,
+        }
+    }
+
+    // the sizes are keyed by label ids and keep the label next to the size, so that
+    // distinct labels sharing an id fail to compile instead of merging into one axis
+    fn EinsumInsertSize<sizes, label, dim>(sizes: Dict, label: LabelId, dim: Unsigned) -> Dict {
+        let id: Unsigned = label.LabelId();
+        let found: Maybe = DictGetUnsigned(sizes, id);
+        match found {
+            #[generics(prev_label, size: Unsigned)]
+            Just::<Cons<prev_label, Cons<size, Nil>>> => {
+                AssertSame(prev_label, label);
+                SameDim(size, dim);
+                sizes
+            }
+            Nothing => {
+                let entry = Cons::<label, Cons<dim, Nil>>;
+                DictInsert(sizes, id, entry)
+            }
+        }
+    }
+
+    fn EinsumOutput<sizes, output>(sizes: Dict, output: List) -> List {
+        match output {
+            #[generics(label: LabelId, tail: List)]
+            Cons::<label, tail> => {
+                let id: Unsigned = label.LabelId();
+                let found: Maybe = DictGetUnsigned(sizes, id);
+                let dim: Unsigned = EinsumOutputDim(found, label);
+                let new_tail: List = EinsumOutput(sizes, tail);
+                Cons::<dim, new_tail>
+            }
+            Nil => Nil,
+        }
+    }
+
+    fn EinsumOutputDim<found, label>(found: Maybe, label: LabelId) -> Unsigned {
+        match found {
+            #[generics(prev_label, size: Unsigned)]
+            Just::<Cons<prev_label, Cons<size, Nil>>> => {
+                AssertSame(prev_label, label);
+                size
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{control::SameOp, dyn_::Dyn, Char, List, Permute};
+    use typenum::consts::*;
+
+    #[test]
+    fn shape_test() {
+        let _: SameOp<NumElementsOp<List![]>, U1> = ();
+        let _: SameOp<NumElementsOp<List![U2, U3, U4]>, U24> = ();
+        let _: SameOp<CanReshapeOp<List![U2, U3, U4], List![U6, U4]>, B1> = ();
+        let _: SameOp<CanReshapeOp<List![U2, U3], List![U5]>, B0> = ();
+        let _: SameOp<CanReshapeOp<List![U1], List![]>, B1> = ();
+    }
+
+    #[test]
+    fn resolve_reshape_test() {
+        let _: SameOp<ResolveReshapeOp<List![U2, U3, U4], List![U6, U4]>, List![U6, U4]> = ();
+        let _: SameOp<ResolveReshapeOp<List![U2, U3, U4], List![Infer]>, List![U24]> = ();
+        let _: SameOp<ResolveReshapeOp<List![U2, U3, U4], List![Infer, U4]>, List![U6, U4]> = ();
+        let _: SameOp<
+            ResolveReshapeOp<List![U2, U3, U4], List![U2, Infer, U3]>,
+            List![U2, U4, U3],
+        > = ();
+        let _: SameOp<ResolveReshapeOp<List![], List![Infer]>, List![U1]> = ();
+    }
+
+    #[test]
+    fn conv_test() {
+        let _: SameOp<ConvOutputDimOp<U32, U3, U1, U1, U1>, U32> = ();
+        let _: SameOp<ConvOutputDimOp<U32, U3, U2, U1, U1>, U16> = ();
+        let _: SameOp<ConvOutputDimOp<U7, U3, U2, U0, U2>, U2> = ();
+        let _: SameOp<ConvOutputDimOp<U8, U2, U2, U0, U1>, U4> = ();
+        let _: SameOp<ConvTransposeOutputDimOp<U16, U3, U2, U1, U1, U1>, U32> = ();
+        let _: SameOp<ConvTransposeOutputDimOp<U4, U2, U2, U0, U1, U0>, U8> = ();
+        let _: SameOp<SamePaddingOp<U32, U3, U1, U1>, U1> = ();
+        let _: SameOp<SamePaddingOp<U32, U5, U1, U2>, U4> = ();
+        let _: SameOp<SamePaddingOp<U33, U3, U2, U1>, U1> = ();
+        let _: SameOp<SamePaddingOp<U4, U1, U2, U1>, U0> = ();
+        let _: SameOp<SamePaddingOp<U32, U3, U2, U1>, U1> = ();
+        let _: SameOp<SamePaddingOp<U7, U5, U3, U1>, U2> = ();
+        let _: SameOp<SamePaddingOp<U10, U2, U3, U1>, U1> = ();
+        let _: SameOp<
+            ConvOutputShapeOp<
+                List![U32, U7],
+                List![U3, U3],
+                List![U2, U2],
+                List![U1, U0],
+                List![U1, U2],
+            >,
+            List![U16, U2],
+        > = ();
+        let _: SameOp<
+            ConvTransposeOutputShapeOp<
+                List![U16, U4],
+                List![U3, U2],
+                List![U2, U2],
+                List![U1, U0],
+                List![U1, U1],
+                List![U1, U0],
+            >,
+            List![U32, U8],
+        > = ();
+    }
+
+    #[test]
+    fn slice_shape_test() {
+        let _: SameOp<SliceShapeOp<List![U4, U5], List![]>, List![U4, U5]> = ();
+        let _: SameOp<SliceShapeOp<List![U4, U5], List![Range<(U1, U3)>]>, List![U2, U5]> = ();
+        let _: SameOp<
+            SliceShapeOp<List![U4, U5, U6], List![RangeFull, RangeFrom<U2>, RangeTo<U6>]>,
+            List![U4, U3, U6],
+        > = ();
+        let _: SameOp<
+            SliceShapeOp<List![U4, U5], List![RangeInclusive<(U1, U3)>, RangeToInclusive<U4>]>,
+            List![U3, U5],
+        > = ();
+        let _: SameOp<SliceShapeOp<List![U4], List![Range<(U2, U2)>]>, List![U0]> = ();
+        let _: SameOp<
+            SliceShapeOp<List![U10, U9], List![StepRange<U0, U10, U3>, StepRange<U1, U9, U2>]>,
+            List![U4, U4],
+        > = ();
+    }
+
+    #[test]
+    fn strides_test() {
+        let _: SameOp<RowMajorStridesOp<List![U2, U3, U4]>, List![U12, U4, U1]> = ();
+        let _: SameOp<ColMajorStridesOp<List![U2, U3, U4]>, List![U1, U2, U6]> = ();
+        let _: SameOp<RowMajorStridesOp<List![]>, List![]> = ();
+        let _: SameOp<RowMajorStridesOp<List![U2, Dyn<usize>, U4]>, List![Dyn<usize>, U4, U1]> = ();
+        let _: SameOp<IsContiguousOp<List![U2, U3, U4], List![U12, U4, U1]>, B1> = ();
+        let _: SameOp<IsContiguousOp<List![U2, U3, U4], List![U1, U2, U6]>, B0> = ();
+        let _: SameOp<IsContiguousOp<List![U2, U1, U4], List![U4, U7, U1]>, B1> = ();
+        let _: SameOp<LinearOffsetOp<List![U12, U4, U1], List![U1, U2, U3]>, U23> = ();
+        let _: SameOp<LinearOffsetOp<List![], List![]>, U0> = ();
+    }
+
+    #[test]
+    fn permutation_test() {
+        struct A;
+        struct B;
+        struct C;
+
+        let _: SameOp<IsPermutationOp<List![]>, B1> = ();
+        let _: SameOp<IsPermutationOp<List![U2, U0, U1]>, B1> = ();
+        let _: SameOp<IsPermutationOp<List![U0, U0, U1]>, B0> = ();
+        let _: SameOp<IsPermutationOp<List![U0, U3, U1]>, B0> = ();
+        let _: SameOp<PermApplyOp<List![U2, U0, U1], List![A, B, C]>, List![C, A, B]> = ();
+        let _: SameOp<PermInverseOp<List![U2, U0, U1]>, List![U1, U2, U0]> = ();
+        let _: SameOp<PermInverseOp<List![]>, List![]> = ();
+        let _: SameOp<PermComposeOp<List![U1, U0, U2], List![U0, U2, U1]>, List![U1, U2, U0]> = ();
+        let _: SameOp<
+            PermApplyOp<PermComposeOp<List![U1, U0, U2], List![U0, U2, U1]>, List![A, B, C]>,
+            PermApplyOp<List![U0, U2, U1], PermApplyOp<List![U1, U0, U2], List![A, B, C]>>,
+        > = ();
+        let _: SameOp<ArgsortOp<List![U3, U1, U2]>, List![U1, U2, U0]> = ();
+        let _: SameOp<ArgsortOp<List![U2, U1, U2, U0]>, List![U3, U1, U0, U2]> = ();
+        let _: SameOp<ArgsortOp<List![]>, List![]> = ();
+        let _: SameOp<Permute![2, 0, 1], List![U2, U0, U1]> = ();
+        let _: SameOp<Permute![List![A, B, C]; 2, 0, 1], List![C, A, B]> = ();
+    }
+
+    #[test]
+    fn einsum_test() {
+        struct I;
+        struct J;
+        struct K;
+
+        impl LabelId for I {
+            type Output = U0;
+        }
+        impl LabelId for J {
+            type Output = U1;
+        }
+        impl LabelId for K {
+            type Output = U2;
+        }
+
+        let _: SameOp<
+            EinsumOp<
+                List![List![I, J], List![J, K]],
+                List![I, K],
+                List![List![U2, U3], List![U3, U4]],
+            >,
+            List![U2, U4],
+        > = ();
+        let _: SameOp<EinsumOp<List![List![I, I]], List![], List![List![U3, U3]]>, List![]> = ();
+        let _: SameOp<
+            EinsumOp<List![List![I, J]], List![J, I], List![List![U2, U5]]>,
+            List![U5, U2],
+        > = ();
+        let _: SameOp<
+            EinsumOp<
+                List![List![Char!('b'), Char!('i')], List![Char!('b'), Char!('j')]],
+                List![Char!('b'), Char!('i'), Char!('j')],
+                List![List![U8, U2], List![U8, U3]],
+            >,
+            List![U8, U2, U3],
+        > = ();
+    }
+
+    #[test]
+    fn broadcast_test() {
+        let _: SameOp<BroadcastOp<List![U2, U3], List![U2, U3]>, List![U2, U3]> = ();
+        let _: SameOp<BroadcastOp<List![U2, U1], List![U1, U3]>, List![U2, U3]> = ();
+        let _: SameOp<BroadcastOp<List![U4, U2, U3], List![U3]>, List![U4, U2, U3]> = ();
+        let _: SameOp<BroadcastOp<List![U1], List![U5, U4]>, List![U5, U4]> = ();
+        let _: SameOp<BroadcastOp<List![], List![U5, U4]>, List![U5, U4]> = ();
+    }
+
+    #[test]
+    fn mat_mul_shape_test() {
+        let _: SameOp<MatMulShapeOp<List![U3], List![U3]>, List![]> = ();
+        let _: SameOp<MatMulShapeOp<List![U3], List![U3, U4]>, List![U4]> = ();
+        let _: SameOp<MatMulShapeOp<List![U2, U3], List![U3]>, List![U2]> = ();
+        let _: SameOp<MatMulShapeOp<List![U2, U3], List![U3, U4]>, List![U2, U4]> = ();
+        let _: SameOp<
+            MatMulShapeOp<List![U5, U1, U2, U3], List![U6, U3, U4]>,
+            List![U5, U6, U2, U4],
+        > = ();
+    }
+
+    #[test]
+    fn axis_test() {
+        let _: SameOp<SqueezeOp<List![U2, U1, U3], U1>, List![U2, U3]> = ();
+        let _: SameOp<SqueezeOp<List![U1], U0>, List![]> = ();
+        let _: SameOp<UnsqueezeOp<List![U2, U3], U0>, List![U1, U2, U3]> = ();
+        let _: SameOp<UnsqueezeOp<List![U2, U3], U2>, List![U2, U3, U1]> = ();
+        let _: SameOp<ConcatShapeOp<List![U2, U3], List![U4, U3], U0>, List![U6, U3]> = ();
+        let _: SameOp<ConcatShapeOp<List![U2, U3], List![U2, U5], U1>, List![U2, U8]> = ();
+        let _: SameOp<TransposeOp<List![U2, U3, U4], List![U2, U0, U1]>, List![U4, U2, U3]> = ();
+        let _: SameOp<TransposeOp<List![], List![]>, List![]> = ();
+    }
+}
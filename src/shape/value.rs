@@ -0,0 +1,542 @@
+//! Value-level drivers of shape operators on `list!` values.
+
+use super::{BroadcastDim, BroadcastDimOp, IsPermutation, IsPermutationOp, StepRange};
+use crate::{
+    common::*,
+    control::{AssertSame, AssertTrue},
+    dyn_::{Dyn, ToUsize},
+    list::{Cons, Len, LenOp, List, Nil, ValueGet, ValueReverse},
+};
+
+// linear offset
+
+/// Computes the linear offset of a runtime multi-index from a list of static and
+/// `Dyn<usize>` strides, matching [LinearOffset](super::LinearOffset).
+///
+/// It panics if the index length differs from the number of strides.
+pub trait ValueLinearOffset
+where
+    Self: List,
+{
+    fn offset(&self, index: &[usize]) -> usize;
+}
+
+impl<Head, Tail> ValueLinearOffset for Cons<Head, Tail>
+where
+    Head: ToUsize,
+    Tail: List + ValueLinearOffset,
+{
+    fn offset(&self, index: &[usize]) -> usize {
+        let (position, index_tail) = index
+            .split_first()
+            .expect("the index has fewer items than the strides");
+        self.head.to_usize() * position + self.tail.offset(index_tail)
+    }
+}
+
+impl ValueLinearOffset for Nil {
+    fn offset(&self, index: &[usize]) -> usize {
+        assert!(
+            index.is_empty(),
+            "the index has more items than the strides"
+        );
+        0
+    }
+}
+
+// permutation
+
+/// Reorders a list by the static permutation `Perm`, cloning the items, and matches the
+/// output type of [PermApply](super::PermApply).
+pub trait ValuePermApply<Perm>
+where
+    Self: List,
+    Perm: List,
+{
+    type Output: List;
+
+    fn perm_apply(&self) -> Self::Output;
+}
+
+impl<Input, Index, PermTail> ValuePermApply<Cons<Index, PermTail>> for Input
+where
+    Input: List + ValueGet<Index> + ValuePermApply<PermTail>,
+    <Input as ValueGet<Index>>::Output: Clone,
+    PermTail: List,
+{
+    type Output =
+        Cons<<Input as ValueGet<Index>>::Output, <Input as ValuePermApply<PermTail>>::Output>;
+
+    fn perm_apply(&self) -> Self::Output {
+        Cons {
+            head: ValueGet::<Index>::get(self).clone(),
+            tail: ValuePermApply::<PermTail>::perm_apply(self),
+        }
+    }
+}
+
+impl<Input> ValuePermApply<Nil> for Input
+where
+    Input: List,
+{
+    type Output = Nil;
+
+    fn perm_apply(&self) -> Self::Output {
+        Nil
+    }
+}
+
+/// Reorders `list!` values by a permutation given as a type parameter.
+pub trait ValuePermute
+where
+    Self: List,
+{
+    /// Reorders the items by `Perm`, which must be a permutation of the item indices.
+    ///
+    /// A permutation of a different length fails to compile.
+    ///
+    /// ```compile_fail
+    /// use type_freak::{list, shape::ValuePermute, Permute};
+    ///
+    /// let _ = list![1u8, 2u16, 3u32].permute::<Permute![1, 0]>();
+    /// ```
+    fn permute<Perm>(&self) -> <Self as ValuePermApply<Perm>>::Output
+    where
+        Self: ValuePermApply<Perm>,
+        Perm: List,
+        (): IsPermutation<Perm> + AssertTrue<IsPermutationOp<Perm>>,
+        (): Len<Self> + Len<Perm> + AssertSame<LenOp<Self>, LenOp<Perm>>,
+    {
+        ValuePermApply::<Perm>::perm_apply(self)
+    }
+}
+
+impl<Input> ValuePermute for Input where Input: List {}
+
+// broadcast
+
+/// Broadcasts a pair of static or `Dyn<usize>` dimensions, matching
+/// [BroadcastDim](super::BroadcastDim) on static dimensions.
+///
+/// Static pairs are checked at compile time, and pairs with a `Dyn` are checked at
+/// runtime, where it panics on incompatible sizes.
+pub trait DimBroadcast<Rhs> {
+    type Output;
+
+    fn broadcast(&self, rhs: &Rhs) -> Self::Output;
+}
+
+macro_rules! static_dim_broadcast_impl {
+    ([$($generics:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<$($generics)*> DimBroadcast<$rhs> for $lhs
+        where
+            (): BroadcastDim<$lhs, $rhs>,
+            BroadcastDimOp<$lhs, $rhs>: Default,
+        {
+            type Output = BroadcastDimOp<$lhs, $rhs>;
+
+            fn broadcast(&self, _rhs: &$rhs) -> Self::Output {
+                Default::default()
+            }
+        }
+    };
+}
+
+macro_rules! dyn_dim_broadcast_impl {
+    ([$($generics:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<$($generics)*> DimBroadcast<$rhs> for $lhs
+        where
+            $lhs: ToUsize,
+            $rhs: ToUsize,
+        {
+            type Output = Dyn<usize>;
+
+            fn broadcast(&self, rhs: &$rhs) -> Self::Output {
+                Dyn(broadcast_dim(self.to_usize(), rhs.to_usize()))
+            }
+        }
+    };
+}
+
+static_dim_broadcast_impl!([] UTerm, UTerm);
+static_dim_broadcast_impl!([U, B] UTerm, UInt<U, B>);
+static_dim_broadcast_impl!([U, B] UInt<U, B>, UTerm);
+static_dim_broadcast_impl!([LU, LB, RU, RB] UInt<LU, LB>, UInt<RU, RB>);
+dyn_dim_broadcast_impl!([] Dyn<usize>, Dyn<usize>);
+dyn_dim_broadcast_impl!([] Dyn<usize>, UTerm);
+dyn_dim_broadcast_impl!([U, B] Dyn<usize>, UInt<U, B>);
+dyn_dim_broadcast_impl!([] UTerm, Dyn<usize>);
+dyn_dim_broadcast_impl!([U, B] UInt<U, B>, Dyn<usize>);
+
+fn broadcast_dim(lhs: usize, rhs: usize) -> usize {
+    if lhs == 1 {
+        rhs
+    } else if rhs == 1 || lhs == rhs {
+        lhs
+    } else {
+        panic!("cannot broadcast sizes {} and {}", lhs, rhs)
+    }
+}
+
+/// Broadcasts shapes of static and `Dyn<usize>` dimensions by NumPy rules, matching
+/// [Broadcast](super::Broadcast) on static shapes.
+pub trait ValueBroadcast<Rhs>
+where
+    Self: List,
+    Rhs: List,
+{
+    type Output: List;
+
+    fn broadcast(&self, rhs: &Rhs) -> Self::Output;
+}
+
+impl<Lhs, Rhs> ValueBroadcast<Rhs> for Lhs
+where
+    Lhs: List + Clone + ValueReverse,
+    Rhs: List + Clone + ValueReverse,
+    <Lhs as ValueReverse>::Output: BroadcastReversed<<Rhs as ValueReverse>::Output>,
+    <<Lhs as ValueReverse>::Output as BroadcastReversed<<Rhs as ValueReverse>::Output>>::Output:
+        ValueReverse,
+{
+    type Output = <<<Lhs as ValueReverse>::Output as BroadcastReversed<
+        <Rhs as ValueReverse>::Output,
+    >>::Output as ValueReverse>::Output;
+
+    fn broadcast(&self, rhs: &Rhs) -> Self::Output {
+        let lhs = self.clone().reverse();
+        let rhs = rhs.clone().reverse();
+        lhs.broadcast_reversed(rhs).reverse()
+    }
+}
+
+#[doc(hidden)]
+pub trait BroadcastReversed<Rhs>
+where
+    Self: List,
+    Rhs: List,
+{
+    type Output: List;
+
+    fn broadcast_reversed(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<LHead, LTail, RHead, RTail> BroadcastReversed<Cons<RHead, RTail>> for Cons<LHead, LTail>
+where
+    LHead: DimBroadcast<RHead>,
+    LTail: List + BroadcastReversed<RTail>,
+    RTail: List,
+{
+    type Output = Cons<LHead::Output, LTail::Output>;
+
+    fn broadcast_reversed(self, rhs: Cons<RHead, RTail>) -> Self::Output {
+        Cons {
+            head: self.head.broadcast(&rhs.head),
+            tail: self.tail.broadcast_reversed(rhs.tail),
+        }
+    }
+}
+
+impl<Head, Tail> BroadcastReversed<Nil> for Cons<Head, Tail>
+where
+    Tail: List,
+{
+    type Output = Self;
+
+    fn broadcast_reversed(self, _rhs: Nil) -> Self::Output {
+        self
+    }
+}
+
+impl<Head, Tail> BroadcastReversed<Cons<Head, Tail>> for Nil
+where
+    Tail: List,
+{
+    type Output = Cons<Head, Tail>;
+
+    fn broadcast_reversed(self, rhs: Cons<Head, Tail>) -> Self::Output {
+        rhs
+    }
+}
+
+impl BroadcastReversed<Nil> for Nil {
+    type Output = Nil;
+
+    fn broadcast_reversed(self, _rhs: Nil) -> Self::Output {
+        Nil
+    }
+}
+
+// slicing
+
+/// A static range of one axis, which is resolved against the axis size at runtime.
+pub trait AxisRange {
+    fn start() -> usize;
+
+    /// The exclusive end of the range on an axis of size `dim`.
+    fn end(dim: usize) -> usize;
+
+    fn step() -> usize {
+        1
+    }
+}
+
+impl<From, To> AxisRange for Range<(From, To)>
+where
+    From: Unsigned,
+    To: Unsigned,
+{
+    fn start() -> usize {
+        From::USIZE
+    }
+
+    fn end(_dim: usize) -> usize {
+        To::USIZE
+    }
+}
+
+impl<From, To> AxisRange for RangeInclusive<(From, To)>
+where
+    From: Unsigned,
+    To: Unsigned,
+{
+    fn start() -> usize {
+        From::USIZE
+    }
+
+    fn end(_dim: usize) -> usize {
+        To::USIZE + 1
+    }
+}
+
+impl<From> AxisRange for RangeFrom<From>
+where
+    From: Unsigned,
+{
+    fn start() -> usize {
+        From::USIZE
+    }
+
+    fn end(dim: usize) -> usize {
+        dim
+    }
+}
+
+impl<To> AxisRange for RangeTo<To>
+where
+    To: Unsigned,
+{
+    fn start() -> usize {
+        0
+    }
+
+    fn end(_dim: usize) -> usize {
+        To::USIZE
+    }
+}
+
+impl<To> AxisRange for RangeToInclusive<To>
+where
+    To: Unsigned,
+{
+    fn start() -> usize {
+        0
+    }
+
+    fn end(_dim: usize) -> usize {
+        To::USIZE + 1
+    }
+}
+
+impl AxisRange for RangeFull {
+    fn start() -> usize {
+        0
+    }
+
+    fn end(dim: usize) -> usize {
+        dim
+    }
+}
+
+impl<From, To, Step> AxisRange for StepRange<From, To, Step>
+where
+    From: Unsigned,
+    To: Unsigned,
+    Step: Unsigned + NonZero,
+{
+    fn start() -> usize {
+        From::USIZE
+    }
+
+    fn end(_dim: usize) -> usize {
+        To::USIZE
+    }
+
+    fn step() -> usize {
+        Step::USIZE
+    }
+}
+
+/// A list of [AxisRange]s, one per leading axis, as accepted by
+/// [SliceShape](super::SliceShape).
+pub trait AxisRanges
+where
+    Self: List,
+{
+    #[doc(hidden)]
+    fn describe(shape: &[usize], descriptor: &mut SliceDescriptor);
+}
+
+impl<Head, Tail> AxisRanges for Cons<Head, Tail>
+where
+    Head: AxisRange,
+    Tail: List + AxisRanges,
+{
+    fn describe(shape: &[usize], descriptor: &mut SliceDescriptor) {
+        let (&dim, shape_tail) = shape.split_first().expect("the ranges outnumber the axes");
+        let start = Head::start();
+        let end = Head::end(dim);
+        let step = Head::step();
+        assert!(
+            start <= end && end <= dim,
+            "the range {}..{} is out of bounds of size {}",
+            start,
+            end,
+            dim
+        );
+
+        descriptor.starts.push(start);
+        descriptor.steps.push(step);
+        descriptor.shape.push((end - start + step - 1) / step);
+        Tail::describe(shape_tail, descriptor);
+    }
+}
+
+impl AxisRanges for Nil {
+    fn describe(shape: &[usize], descriptor: &mut SliceDescriptor) {
+        for &dim in shape {
+            descriptor.starts.push(0);
+            descriptor.steps.push(1);
+            descriptor.shape.push(dim);
+        }
+    }
+}
+
+/// The runtime counterpart of [SliceShape](super::SliceShape), which describes the start,
+/// step and size of the slice on every axis.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SliceDescriptor {
+    pub starts: Vec<usize>,
+    pub steps: Vec<usize>,
+    pub shape: Vec<usize>,
+}
+
+impl SliceDescriptor {
+    /// Resolves the static `Ranges` against a runtime shape.
+    ///
+    /// It panics if a range is out of bounds or there are more ranges than axes.
+    pub fn new<Ranges>(shape: &[usize]) -> Self
+    where
+        Ranges: AxisRanges,
+    {
+        let mut descriptor = Self {
+            starts: vec![],
+            steps: vec![],
+            shape: vec![],
+        };
+        Ranges::describe(shape, &mut descriptor);
+        descriptor
+    }
+
+    /// The linear offset of the first item of the slice given the strides of the source.
+    pub fn offset(&self, strides: &[usize]) -> usize {
+        assert_eq!(strides.len(), self.starts.len(), "the rank mismatches");
+        self.starts
+            .iter()
+            .zip(strides)
+            .map(|(start, stride)| start * stride)
+            .sum()
+    }
+
+    /// The strides of the slice given the strides of the source.
+    pub fn strides(&self, strides: &[usize]) -> Vec<usize> {
+        assert_eq!(strides.len(), self.steps.len(), "the rank mismatches");
+        self.steps
+            .iter()
+            .zip(strides)
+            .map(|(step, stride)| step * stride)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{list, list::ToUsizeVec, List, Permute};
+    use typenum::consts::*;
+
+    #[test]
+    fn value_linear_offset_test() {
+        let strides = list![U12::new(), U4::new(), U1::new()];
+        assert_eq!(strides.offset(&[1, 2, 3]), 23);
+        assert_eq!(strides.offset(&[0, 0, 0]), 0);
+
+        let strides = list![Dyn(20usize), U4::new(), U1::new()];
+        assert_eq!(strides.offset(&[1, 2, 3]), 31);
+        assert_eq!(list![].offset(&[]), 0);
+    }
+
+    #[test]
+    fn value_permute_test() {
+        let list = list![1u8, "a", 2.5f32];
+        let permuted: List![f32, u8, &str] = list.permute::<Permute![2, 0, 1]>();
+        assert_eq!(permuted, list![2.5f32, 1u8, "a"]);
+        assert_eq!(list.permute::<List![]>(), list![]);
+    }
+
+    #[test]
+    fn value_broadcast_test() {
+        let shape: List![U4, U2, U3] = ValueBroadcast::broadcast(
+            &list![U4::new(), U1::new(), U3::new()],
+            &list![U2::new(), U1::new()],
+        );
+        assert_eq!(shape, Default::default());
+
+        let shape =
+            ValueBroadcast::broadcast(&list![Dyn(1usize), U3::new()], &list![U5::new(), U3::new()]);
+        let _: List![Dyn<usize>, U3] = shape;
+        assert_eq!(shape.to_usize_vec(), vec![5, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn value_broadcast_mismatch_test() {
+        ValueBroadcast::broadcast(&list![Dyn(2usize)], &list![U3::new()]);
+    }
+
+    #[test]
+    fn slice_descriptor_test() {
+        let descriptor =
+            SliceDescriptor::new::<List![Range<(U1, U3)>, StepRange<U1, U9, U2>]>(&[4, 9, 6]);
+        assert_eq!(descriptor.starts, vec![1, 1, 0]);
+        assert_eq!(descriptor.steps, vec![1, 2, 1]);
+        assert_eq!(descriptor.shape, vec![2, 4, 6]);
+        assert_eq!(descriptor.offset(&[54, 6, 1]), 60);
+        assert_eq!(descriptor.strides(&[54, 6, 1]), vec![54, 12, 1]);
+
+        let descriptor = SliceDescriptor::new::<List![RangeFull, RangeFrom<U2>]>(&[3, 5]);
+        assert_eq!(descriptor.starts, vec![0, 2]);
+        assert_eq!(descriptor.shape, vec![3, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_descriptor_bounds_test() {
+        SliceDescriptor::new::<List![RangeTo<U5>]>(&[4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn value_linear_offset_length_test() {
+        list![U4::new(), U1::new()].offset(&[1]);
+    }
+}